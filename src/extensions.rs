@@ -0,0 +1,5 @@
+// BLAS-like extensions found in MKL, OpenBLAS and cuBLAS.
+// Matrices are column-major slices with an explicit leading dimension.
+pub mod omatcopy;
+pub mod imatcopy;
+pub mod geam;
@@ -0,0 +1,128 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::types::{op_at, Trans};
+
+// Tile edge used when walking a transposed operand.
+const BLOCK: usize = 64;
+
+pub trait Geam {
+    type Output;
+    // C = alpha * op(A) + beta * op(B), where C is m x n.
+    fn geam(&self, transa: Trans, transb: Trans, m: usize, n: usize, alpha: Self::Output, lda: usize, beta: Self::Output, b: &Self, ldb: usize, c: &mut Self, ldc: usize);
+    // C = alpha * C + beta * op(B): the in-place form, with C taking the place of A.
+    fn geam_inplace(&mut self, transb: Trans, m: usize, n: usize, alpha: Self::Output, ldc: usize, beta: Self::Output, b: &Self, ldb: usize);
+}
+
+impl Geam for [f32] {
+    type Output = f32;
+    fn geam(&self, transa: Trans, transb: Trans, m: usize, n: usize, alpha: f32, lda: usize, beta: f32, b: &Self, ldb: usize, c: &mut Self, ldc: usize) {
+        geam(Some((self, transa, lda)), m, n, alpha, beta, b, transb, ldb, c, ldc)
+    }
+    fn geam_inplace(&mut self, transb: Trans, m: usize, n: usize, alpha: f32, ldc: usize, beta: f32, b: &Self, ldb: usize) {
+        geam(None, m, n, alpha, beta, b, transb, ldb, self, ldc)
+    }
+}
+impl Geam for [f64] {
+    type Output = f64;
+    fn geam(&self, transa: Trans, transb: Trans, m: usize, n: usize, alpha: f64, lda: usize, beta: f64, b: &Self, ldb: usize, c: &mut Self, ldc: usize) {
+        geam(Some((self, transa, lda)), m, n, alpha, beta, b, transb, ldb, c, ldc)
+    }
+    fn geam_inplace(&mut self, transb: Trans, m: usize, n: usize, alpha: f64, ldc: usize, beta: f64, b: &Self, ldb: usize) {
+        geam(None, m, n, alpha, beta, b, transb, ldb, self, ldc)
+    }
+}
+impl Geam for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn geam(&self, transa: Trans, transb: Trans, m: usize, n: usize, alpha: Complex<f32>, lda: usize, beta: Complex<f32>, b: &Self, ldb: usize, c: &mut Self, ldc: usize) {
+        geam(Some((self, transa, lda)), m, n, alpha, beta, b, transb, ldb, c, ldc)
+    }
+    fn geam_inplace(&mut self, transb: Trans, m: usize, n: usize, alpha: Complex<f32>, ldc: usize, beta: Complex<f32>, b: &Self, ldb: usize) {
+        geam(None, m, n, alpha, beta, b, transb, ldb, self, ldc)
+    }
+}
+impl Geam for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn geam(&self, transa: Trans, transb: Trans, m: usize, n: usize, alpha: Complex<f64>, lda: usize, beta: Complex<f64>, b: &Self, ldb: usize, c: &mut Self, ldc: usize) {
+        geam(Some((self, transa, lda)), m, n, alpha, beta, b, transb, ldb, c, ldc)
+    }
+    fn geam_inplace(&mut self, transb: Trans, m: usize, n: usize, alpha: Complex<f64>, ldc: usize, beta: Complex<f64>, b: &Self, ldb: usize) {
+        geam(None, m, n, alpha, beta, b, transb, ldb, self, ldc)
+    }
+}
+
+// When `a` is None the current contents of C act as A (untransposed, leading dimension ldc).
+// As in BLAS, an operand whose scalar is zero is not read.
+fn geam<T: Scalar>(a: Option<(&[T], Trans, usize)>, m: usize, n: usize, alpha: T, beta: T, b: &[T], transb: Trans, ldb: usize, c: &mut [T], ldc: usize) {
+    if m == 0 || n == 0 {
+        return;
+    }
+    let zero = T::zero();
+    c
+    .par_chunks_mut(ldc * BLOCK)
+    .take(n.div_ceil(BLOCK))
+    .enumerate()
+    .for_each(|(jb, c_block)| {
+        let j0 = jb * BLOCK;
+        let j1 = (j0 + BLOCK).min(n);
+        for i0 in (0..m).step_by(BLOCK) {
+            let i1 = (i0 + BLOCK).min(m);
+            for j in j0..j1 {
+                let c_col = &mut c_block[(j - j0) * ldc..];
+                for (i, c_ij) in c_col.iter_mut().enumerate().take(i1).skip(i0) {
+                    let a_term = match a {
+                        _ if alpha == zero => zero,
+                        Some((a, transa, lda)) => alpha * op_at(a, transa, lda, i, j),
+                        None => alpha * *c_ij,
+                    };
+                    let b_term = if beta == zero { zero } else { beta * op_at(b, transb, ldb, i, j) };
+                    *c_ij = a_term + b_term;
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_geam() {
+        // A is 2 x 3 (lda = 2), B is 3 x 2 (ldb = 3)
+        let a_f32: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let b_f32: Vec<f32> = vec![1.0, 1.0, 1.0, 2.0, 2.0, 2.0];
+        let mut c_f32: Vec<f32> = vec![0.0; 8];
+        let a_f64: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+        let mut c_f64: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, -1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)];
+        let b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0); 4];
+        let mut c_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 4];
+        let b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, -1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0); 4];
+
+        // C (2 x 3, ldc = 3) = 2A + B^T
+        a_f32.as_slice().geam(Trans::NoTrans, Trans::Trans, 2, 3, 2.0, 2, 1.0, &b_f32, 3, &mut c_f32, 3);
+        assert_eq!(c_f32, vec![3.0, 6.0, 0.0, 7.0, 10.0, 0.0, 11.0, 14.0]);
+
+        // C = C + A^T
+        c_f64.as_mut_slice().geam_inplace(Trans::Trans, 2, 2, 1.0, 2, 1.0, &a_f64, 2);
+        assert_eq!(c_f64, vec![2.0, 5.0, 5.0, 8.0]);
+
+        // C = A^H - B
+        a_complex_f32.as_slice().geam(Trans::ConjTrans, Trans::NoTrans, 2, 2, Complex::new(1.0, 0.0), 2, Complex::new(-1.0, 0.0), &b_complex_f32, 2, &mut c_complex_f32, 2);
+        assert_eq!(c_complex_f32, vec![Complex::new(0.0, -1.0), Complex::new(2.0, 0.0), Complex::new(1.0, 1.0), Complex::new(-1.0, -4.0)]);
+
+        // C = i * C + B^H
+        c_complex_f64.as_mut_slice().geam_inplace(Trans::ConjTrans, 2, 2, Complex::new(0.0, 1.0), 2, Complex::new(1.0, 0.0), &b_complex_f64, 2);
+        assert_eq!(c_complex_f64, vec![Complex::new(1.0, 0.0), Complex::new(3.0, 1.0), Complex::new(2.0, 2.0), Complex::new(0.0, -3.0)]);
+
+        // Beta of zero ignores B entirely, even when it holds NaN
+        let a = vec![1.0_f64; 4];
+        let b = vec![f64::NAN; 4];
+        let mut c = vec![0.0_f64; 4];
+        a.as_slice().geam(Trans::NoTrans, Trans::NoTrans, 2, 2, 3.0, 2, 0.0, &b, 2, &mut c, 2);
+        assert_eq!(c, vec![3.0; 4]);
+    }
+}
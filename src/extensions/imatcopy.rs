@@ -0,0 +1,191 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::types::Trans;
+
+// Below this size the square transpose stops recursing and swaps element by element.
+const BLOCK: usize = 64;
+
+pub trait Imatcopy {
+    type Output;
+    fn imatcopy(&mut self, trans: Trans, rows: usize, cols: usize, alpha: Self::Output, lda: usize, ldb: usize);
+}
+
+impl Imatcopy for [f32] {
+    type Output = f32;
+    fn imatcopy(&mut self, trans: Trans, rows: usize, cols: usize, alpha: f32, lda: usize, ldb: usize) {
+        imatcopy(self, trans, rows, cols, alpha, lda, ldb)
+    }
+}
+impl Imatcopy for [f64] {
+    type Output = f64;
+    fn imatcopy(&mut self, trans: Trans, rows: usize, cols: usize, alpha: f64, lda: usize, ldb: usize) {
+        imatcopy(self, trans, rows, cols, alpha, lda, ldb)
+    }
+}
+impl Imatcopy for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn imatcopy(&mut self, trans: Trans, rows: usize, cols: usize, alpha: Complex<f32>, lda: usize, ldb: usize) {
+        imatcopy(self, trans, rows, cols, alpha, lda, ldb)
+    }
+}
+impl Imatcopy for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn imatcopy(&mut self, trans: Trans, rows: usize, cols: usize, alpha: Complex<f64>, lda: usize, ldb: usize) {
+        imatcopy(self, trans, rows, cols, alpha, lda, ldb)
+    }
+}
+
+// AB = alpha * op(AB) in place. The input is rows x cols with leading dimension lda,
+// the output uses ldb, so the buffer must be large enough for both layouts.
+fn imatcopy<T: Scalar>(ab: &mut [T], trans: Trans, rows: usize, cols: usize, alpha: T, lda: usize, ldb: usize) {
+    if rows == 0 || cols == 0 {
+        return;
+    }
+    let conj = trans == Trans::ConjTrans;
+    match trans {
+        Trans::NoTrans => {
+            relayout(ab, rows, cols, lda, ldb);
+            scale(ab, rows, cols, ldb, alpha, false);
+        }
+        Trans::Trans | Trans::ConjTrans if rows == cols && lda == ldb => {
+            transpose_square(ab, rows, lda, alpha, conj);
+        }
+        Trans::Trans | Trans::ConjTrans => {
+            // Pack to rows x cols, permute the packed buffer, then spread out to ldb.
+            relayout(ab, rows, cols, lda, rows);
+            transpose_packed(&mut ab[..rows * cols], rows, cols);
+            scale(ab, cols, rows, cols, alpha, conj);
+            relayout(ab, cols, rows, cols, ldb);
+        }
+    }
+}
+
+// Moves each column from stride `from` to stride `to`, ordered so no column overwrites
+// one that has not been moved yet.
+fn relayout<T: Scalar>(ab: &mut [T], rows: usize, cols: usize, from: usize, to: usize) {
+    if from > to {
+        for j in 1..cols {
+            ab.copy_within(j * from..j * from + rows, j * to);
+        }
+    } else if from < to {
+        for j in (1..cols).rev() {
+            ab.copy_within(j * from..j * from + rows, j * to);
+        }
+    }
+}
+
+fn scale<T: Scalar>(ab: &mut [T], rows: usize, cols: usize, ld: usize, alpha: T, conj: bool) {
+    if alpha == T::one() && !conj {
+        return;
+    }
+    ab
+    .par_chunks_mut(ld)
+    .take(cols)
+    .for_each(|col| {
+        col[..rows]
+        .iter_mut()
+        .for_each(|x| *x = alpha * if conj { x.conj() } else { *x });
+    })
+}
+
+// Recursive in-place transpose of an n x n matrix. The two diagonal halves live in
+// disjoint column ranges, so they are transposed in parallel after the off-diagonal
+// blocks are swapped.
+fn transpose_square<T: Scalar>(a: &mut [T], n: usize, lda: usize, alpha: T, conj: bool) {
+    let op = |v: T| alpha * if conj { v.conj() } else { v };
+    if n <= BLOCK {
+        for j in 0..n {
+            a[j + j * lda] = op(a[j + j * lda]);
+            for i in j + 1..n {
+                let upper = a[j + i * lda];
+                a[j + i * lda] = op(a[i + j * lda]);
+                a[i + j * lda] = op(upper);
+            }
+        }
+        return;
+    }
+    let h = n / 2;
+    let (left, right) = a.split_at_mut(h * lda);
+    // A21 is left[h..] (n - h rows, h cols); A12 is right[..] (h rows, n - h cols).
+    for c0 in (0..h).step_by(BLOCK) {
+        for r0 in (0..n - h).step_by(BLOCK) {
+            for c in c0..(c0 + BLOCK).min(h) {
+                for r in r0..(r0 + BLOCK).min(n - h) {
+                    let lower = left[h + r + c * lda];
+                    left[h + r + c * lda] = op(right[c + r * lda]);
+                    right[c + r * lda] = op(lower);
+                }
+            }
+        }
+    }
+    rayon::join(
+        || transpose_square(left, h, lda, alpha, conj),
+        || transpose_square(&mut right[h..], n - h, lda, alpha, conj),
+    );
+}
+
+// Cycle-following transpose of a packed rows x cols matrix into a packed cols x rows one.
+// Element p = i + j * rows moves to j + i * cols = p * cols mod (len - 1).
+fn transpose_packed<T: Scalar>(a: &mut [T], rows: usize, cols: usize) {
+    let len = rows * cols;
+    if rows == 1 || cols == 1 {
+        return;
+    }
+    let mut visited = vec![false; len];
+    for start in 1..len - 1 {
+        if visited[start] {
+            continue;
+        }
+        let mut pos = start;
+        let mut carried = a[start];
+        loop {
+            let next = pos * cols % (len - 1);
+            std::mem::swap(&mut a[next], &mut carried);
+            visited[next] = true;
+            pos = next;
+            if pos == start {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_imatcopy() {
+        let mut ab_f32: Vec<f32> = vec![1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 5.0, 6.0, 0.0];
+        let mut ab_f64: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut ab_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, -1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)];
+        let mut ab_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, -1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)];
+
+        ab_f32.as_mut_slice().imatcopy(Trans::NoTrans, 2, 3, 2.0, 3, 2);
+        assert_eq!(ab_f32[..6], [2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+
+        ab_f64.as_mut_slice().imatcopy(Trans::Trans, 2, 3, 1.0, 2, 3);
+        assert_eq!(ab_f64, vec![1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+
+        ab_complex_f32.as_mut_slice().imatcopy(Trans::Trans, 2, 2, Complex::new(0.0, 1.0), 2, 2);
+        assert_eq!(ab_complex_f32, vec![Complex::new(-1.0, 1.0), Complex::new(0.0, 3.0), Complex::new(1.0, 2.0), Complex::new(-4.0, 0.0)]);
+
+        ab_complex_f64.as_mut_slice().imatcopy(Trans::ConjTrans, 2, 2, Complex::new(1.0, 0.0), 2, 2);
+        assert_eq!(ab_complex_f64, vec![Complex::new(1.0, -1.0), Complex::new(3.0, 0.0), Complex::new(2.0, 1.0), Complex::new(0.0, -4.0)]);
+
+        // Recursive square path and cycle-following path with padded leading dimensions
+        for (rows, cols, lda, ldb) in [(150, 150, 150, 150), (130, 70, 133, 75), (3, 5, 4, 6)] {
+            let a: Vec<f64> = (0..lda * cols).map(|v| v as f64).collect();
+            let mut ab = a.clone();
+            ab.resize((lda * cols).max(ldb * rows), 0.0);
+            ab.as_mut_slice().imatcopy(Trans::Trans, rows, cols, 2.0, lda, ldb);
+            for i in 0..rows {
+                for j in 0..cols {
+                    assert_eq!(ab[j + i * ldb], 2.0 * a[i + j * lda]);
+                }
+            }
+        }
+    }
+}
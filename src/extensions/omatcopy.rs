@@ -0,0 +1,122 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::types::Trans;
+
+// Tile edge for the transposing copy; a BLOCK x BLOCK tile of f64 fits in L1.
+const BLOCK: usize = 64;
+
+pub trait Omatcopy {
+    type Output;
+    fn omatcopy(&self, trans: Trans, rows: usize, cols: usize, alpha: Self::Output, lda: usize, b: &mut Self, ldb: usize);
+}
+
+impl Omatcopy for [f32] {
+    type Output = f32;
+    fn omatcopy(&self, trans: Trans, rows: usize, cols: usize, alpha: f32, lda: usize, b: &mut Self, ldb: usize) {
+        omatcopy(self, trans, rows, cols, alpha, lda, b, ldb)
+    }
+}
+impl Omatcopy for [f64] {
+    type Output = f64;
+    fn omatcopy(&self, trans: Trans, rows: usize, cols: usize, alpha: f64, lda: usize, b: &mut Self, ldb: usize) {
+        omatcopy(self, trans, rows, cols, alpha, lda, b, ldb)
+    }
+}
+impl Omatcopy for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn omatcopy(&self, trans: Trans, rows: usize, cols: usize, alpha: Complex<f32>, lda: usize, b: &mut Self, ldb: usize) {
+        omatcopy(self, trans, rows, cols, alpha, lda, b, ldb)
+    }
+}
+impl Omatcopy for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn omatcopy(&self, trans: Trans, rows: usize, cols: usize, alpha: Complex<f64>, lda: usize, b: &mut Self, ldb: usize) {
+        omatcopy(self, trans, rows, cols, alpha, lda, b, ldb)
+    }
+}
+
+// B = alpha * op(A), where A is rows x cols and B is rows x cols or cols x rows.
+pub(crate) fn omatcopy<T: Scalar>(a: &[T], trans: Trans, rows: usize, cols: usize, alpha: T, lda: usize, b: &mut [T], ldb: usize) {
+    if rows == 0 || cols == 0 {
+        return;
+    }
+    match trans {
+        Trans::NoTrans => {
+            b
+            .par_chunks_mut(ldb)
+            .take(cols)
+            .enumerate()
+            .for_each(|(j, b_col)| {
+                b_col[..rows]
+                .iter_mut()
+                .zip(&a[j * lda..j * lda + rows])
+                .for_each(|(b, &a)| *b = alpha * a);
+            })
+        }
+        Trans::Trans | Trans::ConjTrans => {
+            let conj = trans == Trans::ConjTrans;
+            // Each task owns BLOCK columns of B (BLOCK rows of A) and walks A tile by tile.
+            b
+            .par_chunks_mut(ldb * BLOCK)
+            .take(rows.div_ceil(BLOCK))
+            .enumerate()
+            .for_each(|(ib, b_block)| {
+                let i0 = ib * BLOCK;
+                let i1 = (i0 + BLOCK).min(rows);
+                for j0 in (0..cols).step_by(BLOCK) {
+                    let j1 = (j0 + BLOCK).min(cols);
+                    for i in i0..i1 {
+                        let b_col = &mut b_block[(i - i0) * ldb..];
+                        for j in j0..j1 {
+                            let v = a[i + j * lda];
+                            b_col[j] = alpha * if conj { v.conj() } else { v };
+                        }
+                    }
+                }
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_omatcopy() {
+        // 2 x 3 matrices stored with lda = 3
+        let a_f32: Vec<f32> = vec![1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 5.0, 6.0, 0.0];
+        let mut b_f32: Vec<f32> = vec![0.0; 6];
+        let a_f64: Vec<f64> = vec![1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 5.0, 6.0, 0.0];
+        let mut b_f64: Vec<f64> = vec![-1.0; 8];
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, -1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)];
+        let mut b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 4];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, -1.0), Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)];
+        let mut b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 4];
+
+        a_f32.as_slice().omatcopy(Trans::NoTrans, 2, 3, 2.0, 3, &mut b_f32, 2);
+        assert_eq!(b_f32, vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+
+        a_f64.as_slice().omatcopy(Trans::Trans, 2, 3, 1.0, 3, &mut b_f64, 4);
+        assert_eq!(b_f64, vec![1.0, 3.0, 5.0, -1.0, 2.0, 4.0, 6.0, -1.0]);
+
+        a_complex_f32.as_slice().omatcopy(Trans::Trans, 2, 2, Complex::new(0.0, 1.0), 2, &mut b_complex_f32, 2);
+        assert_eq!(b_complex_f32, vec![Complex::new(-1.0, 1.0), Complex::new(0.0, 3.0), Complex::new(1.0, 2.0), Complex::new(-4.0, 0.0)]);
+
+        a_complex_f64.as_slice().omatcopy(Trans::ConjTrans, 2, 2, Complex::new(1.0, 0.0), 2, &mut b_complex_f64, 2);
+        assert_eq!(b_complex_f64, vec![Complex::new(1.0, -1.0), Complex::new(3.0, 0.0), Complex::new(2.0, 1.0), Complex::new(0.0, -4.0)]);
+
+        // Spans several tiles in both directions
+        let (rows, cols) = (150, 70);
+        let a: Vec<f64> = (0..rows * cols).map(|v| v as f64).collect();
+        let mut b = vec![0.0; rows * cols];
+        a.as_slice().omatcopy(Trans::Trans, rows, cols, 1.0, rows, &mut b, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                assert_eq!(b[j + i * cols], a[i + j * rows]);
+            }
+        }
+    }
+}
@@ -8,7 +8,22 @@ pub mod cabs;
 // Two vector operations
 pub mod asum;
 pub mod axpy;
+pub mod axpby;
+pub mod waxpby;
 pub mod dot;
 pub mod rot;
 // pub mod copy;    // Use clone or to_vec for copying vector slices
 // pub mod swap;    // Use slice::swap or slice::swap_with_slice for swapping vector slices
+
+// Batched operations over many independent vectors
+pub mod dot_batch;
+pub mod nrm2_batch;
+pub mod axpy_batch;
+pub mod scal_batch;
+
+// Sparse Level 1: a sparse vector against a dense one
+pub mod usdot;
+pub mod usaxpy;
+pub mod usga;
+pub mod usgz;
+pub mod ussc;
@@ -0,0 +1,102 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+
+pub trait Axpby {
+    type Output;
+    fn axpby(&self, a: Self::Output, incx: usize, b: Self::Output, y: &mut Self, incy: usize);
+}
+
+impl Axpby for [f32] {
+    type Output = f32;
+    fn axpby(&self, a: f32, incx: usize, b: f32, y: &mut Self, incy: usize) {
+        self
+        .par_iter()
+        .step_by(incx)
+        .zip(
+            y
+            .par_iter_mut()
+            .step_by(incy)
+        )
+        .for_each(|(x, y)| {
+            *y = a * *x + b * *y;
+        })
+    }
+}
+impl Axpby for [f64] {
+    type Output = f64;
+    fn axpby(&self, a: f64, incx: usize, b: f64, y: &mut Self, incy: usize) {
+        self
+        .par_iter()
+        .step_by(incx)
+        .zip(
+            y
+            .par_iter_mut()
+            .step_by(incy)
+        )
+        .for_each(|(x, y)| {
+            *y = a * *x + b * *y;
+        })
+    }
+}
+impl Axpby for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn axpby(&self, a: Complex<f32>, incx: usize, b: Complex<f32>, y: &mut Self, incy: usize) {
+        self
+        .par_iter()
+        .step_by(incx)
+        .zip(
+            y
+            .par_iter_mut()
+            .step_by(incy)
+        )
+        .for_each(|(x, y)| {
+            *y = a * *x + b * *y;
+        })
+    }
+}
+impl Axpby for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn axpby(&self, a: Complex<f64>, incx: usize, b: Complex<f64>, y: &mut Self, incy: usize) {
+        self
+        .par_iter()
+        .step_by(incx)
+        .zip(
+            y
+            .par_iter_mut()
+            .step_by(incy)
+        )
+        .for_each(|(x, y)| {
+            *y = a * *x + b * *y;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_axpby() {
+        let x_f32: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut y_f32: Vec<f32> = vec![2.0; 5];
+        let x_f64 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut y_f64 = vec![2.0; 5];
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+        let mut y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0); 2];
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+        let mut y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0); 2];
+
+        x_f32.as_slice().axpby(2.0, 1, 3.0, &mut y_f32, 1);
+        assert_eq!(y_f32, vec![8.0, 10.0, 12.0, 14.0, 16.0]);
+
+        x_f64.as_slice().axpby(2.0, 2, 0.5, &mut y_f64, 2);
+        assert_eq!(y_f64, vec![3.0, 2.0, 7.0, 2.0, 11.0]);
+
+        x_complex_f32.as_slice().axpby(Complex::new(1.0, 0.0), 1, Complex::new(0.0, 1.0), &mut y_complex_f32, 1);
+        assert_eq!(y_complex_f32, vec![Complex::new(0.0, 3.0), Complex::new(2.0, 5.0)]);
+
+        x_complex_f64.as_slice().axpby(Complex::new(1.0, 0.0), 1, Complex::new(0.0, 1.0), &mut y_complex_f64, 1);
+        assert_eq!(y_complex_f64, vec![Complex::new(0.0, 3.0), Complex::new(2.0, 5.0)]);
+    }
+}
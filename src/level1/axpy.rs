@@ -18,7 +18,7 @@ impl Axpy for [f32] {
             .step_by(incy)
         )
         .for_each(|(x, y)| {
-            *y = a * *x + *y;
+            *y += a * *x;
         })
     }
 }
@@ -34,7 +34,7 @@ impl Axpy for [f64] {
             .step_by(incy)
         )
         .for_each(|(x, y)| {
-            *y = a * *x + *y;
+            *y += a * *x;
         })
     }
 }
@@ -50,7 +50,7 @@ impl Axpy for [Complex<f32>] {
             .step_by(incy)
         )
         .for_each(|(x, y)| {
-            *y = a * *x + *y;
+            *y += a * *x;
         })
     }
 }
@@ -66,7 +66,7 @@ impl Axpy for [Complex<f64>] {
             .step_by(incy)
         )
         .for_each(|(x, y)| {
-            *y = a * *x + *y;
+            *y += a * *x;
         })
     }
 }
@@ -0,0 +1,179 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+
+pub trait AxpyBatch {
+    type Output;
+    fn axpy_batch(&self, a: &[Self::Output], incx: usize, y: &mut [&mut [Self::Output]], incy: usize);
+}
+
+pub trait AxpyStridedBatch {
+    type Output;
+    fn axpy_strided_batch(&self, n: usize, a: Self::Output, incx: usize, stridex: usize, y: &mut Self, incy: usize, stridey: usize, batch_count: usize);
+}
+
+impl AxpyBatch for [&[f32]] {
+    type Output = f32;
+    fn axpy_batch(&self, a: &[f32], incx: usize, y: &mut [&mut [f32]], incy: usize) {
+        axpy_batch(self, a, incx, y, incy)
+    }
+}
+impl AxpyBatch for [&[f64]] {
+    type Output = f64;
+    fn axpy_batch(&self, a: &[f64], incx: usize, y: &mut [&mut [f64]], incy: usize) {
+        axpy_batch(self, a, incx, y, incy)
+    }
+}
+impl AxpyBatch for [&[Complex<f32>]] {
+    type Output = Complex<f32>;
+    fn axpy_batch(&self, a: &[Complex<f32>], incx: usize, y: &mut [&mut [Complex<f32>]], incy: usize) {
+        axpy_batch(self, a, incx, y, incy)
+    }
+}
+impl AxpyBatch for [&[Complex<f64>]] {
+    type Output = Complex<f64>;
+    fn axpy_batch(&self, a: &[Complex<f64>], incx: usize, y: &mut [&mut [Complex<f64>]], incy: usize) {
+        axpy_batch(self, a, incx, y, incy)
+    }
+}
+
+impl AxpyStridedBatch for [f32] {
+    type Output = f32;
+    fn axpy_strided_batch(&self, n: usize, a: f32, incx: usize, stridex: usize, y: &mut Self, incy: usize, stridey: usize, batch_count: usize) {
+        axpy_strided_batch(self, n, a, incx, stridex, y, incy, stridey, batch_count)
+    }
+}
+impl AxpyStridedBatch for [f64] {
+    type Output = f64;
+    fn axpy_strided_batch(&self, n: usize, a: f64, incx: usize, stridex: usize, y: &mut Self, incy: usize, stridey: usize, batch_count: usize) {
+        axpy_strided_batch(self, n, a, incx, stridex, y, incy, stridey, batch_count)
+    }
+}
+impl AxpyStridedBatch for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn axpy_strided_batch(&self, n: usize, a: Complex<f32>, incx: usize, stridex: usize, y: &mut Self, incy: usize, stridey: usize, batch_count: usize) {
+        axpy_strided_batch(self, n, a, incx, stridex, y, incy, stridey, batch_count)
+    }
+}
+impl AxpyStridedBatch for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn axpy_strided_batch(&self, n: usize, a: Complex<f64>, incx: usize, stridex: usize, y: &mut Self, incy: usize, stridey: usize, batch_count: usize) {
+        axpy_strided_batch(self, n, a, incx, stridex, y, incy, stridey, batch_count)
+    }
+}
+
+// y[i] += a[i] * x[i] for every i, one update per task.
+fn axpy_batch<T: Scalar>(x: &[&[T]], a: &[T], incx: usize, y: &mut [&mut [T]], incy: usize) {
+    assert_eq!(x.len(), y.len(), "x and y must hold the same number of vectors");
+    assert_eq!(a.len(), y.len(), "a must hold one scalar for every vector");
+    y
+    .par_iter_mut()
+    .zip(x)
+    .zip(a)
+    .for_each(|((y, x), &a)| axpy(x, a, incx, y, incy, usize::MAX))
+}
+
+// y_i += a * x_i for i < batch_count, where x_i starts at x[i * stridex] and y_i at
+// y[i * stridey], both of length n. A zero stridex adds one x to every y_i; the y_i must
+// not overlap, but may interleave.
+fn axpy_strided_batch<T: Scalar>(x: &[T], n: usize, a: T, incx: usize, stridex: usize, y: &mut [T], incy: usize, stridey: usize, batch_count: usize) {
+    if batch_count == 0 || n == 0 {
+        return;
+    }
+    let span = (n - 1) * incy + 1;
+    if batch_count == 1 || stridey >= span {
+        // Each y_i lies within its own stride, so the vectors are updated in parallel.
+        y
+        .par_chunks_mut(stridey.max(span))
+        .take(batch_count)
+        .enumerate()
+        .for_each(|(i, y)| axpy(&x[i * stridex..], a, incx, y, incy, n))
+    } else if stridey > 0 && incy > (batch_count - 1) * stridey {
+        // Interleaved vectors, such as the rows of a column-major matrix: element r of every
+        // y_i lies within one increment, so the elements are updated in parallel instead.
+        y
+        .par_chunks_mut(incy)
+        .take(n)
+        .enumerate()
+        .for_each(|(r, y)| {
+            for i in 0..batch_count {
+                y[i * stridey] += a * x[i * stridex + r * incx];
+            }
+        })
+    } else {
+        // Neither separated nor fully interleaved, such as stride 4 with increment 3: disjoint
+        // vectors are then updated one after another.
+        assert!(!overlapping(n, incy, stridey, batch_count), "the y vectors must not overlap");
+        for i in 0..batch_count {
+            axpy(&x[i * stridex..], a, incx, &mut y[i * stridey..], incy, n);
+        }
+    }
+}
+
+// Whether some element of the length-n vector starting at i * stride, with increment inc, is
+// shared with another for i < batch_count: vectors d apart collide exactly when d * stride is
+// a multiple of inc reached within n elements.
+pub(crate) fn overlapping(n: usize, inc: usize, stride: usize, batch_count: usize) -> bool {
+    (1..batch_count).any(|d| {
+        let gap = d * stride;
+        if inc == 0 { gap == 0 } else { gap.is_multiple_of(inc) && gap / inc < n }
+    })
+}
+
+fn axpy<T: Scalar>(x: &[T], a: T, incx: usize, y: &mut [T], incy: usize, n: usize) {
+    x
+    .iter()
+    .step_by(incx)
+    .zip(
+        y
+        .iter_mut()
+        .step_by(incy)
+    )
+    .take(n)
+    .for_each(|(&x, y)| *y += a * x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_axpy_batch() {
+        let x0_f32: Vec<f32> = vec![1.0, 2.0];
+        let x1_f32: Vec<f32> = vec![3.0];
+        let mut y0_f32: Vec<f32> = vec![1.0, 1.0];
+        let mut y1_f32: Vec<f32> = vec![1.0];
+        // One x added to both columns of a 2 x 2 matrix
+        let x_f64: Vec<f64> = vec![1.0, 2.0];
+        let mut y_f64: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 1.0)];
+        let mut y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)];
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+
+        [x0_f32.as_slice(), &x1_f32].axpy_batch(&[2.0, -1.0], 1, &mut [&mut y0_f32, &mut y1_f32], 1);
+        assert_eq!(y0_f32, vec![3.0, 5.0]);
+        assert_eq!(y1_f32, vec![-2.0]);
+
+        x_f64.axpy_strided_batch(2, 10.0, 1, 0, &mut y_f64, 1, 2, 2);
+        assert_eq!(y_f64, vec![11.0, 22.0, 13.0, 24.0]);
+
+        [x_complex_f32.as_slice()].axpy_batch(&[Complex::new(0.0, 1.0)], 1, &mut [&mut y_complex_f32], 1);
+        assert_eq!(y_complex_f32, vec![Complex::new(0.0, 1.0), Complex::new(0.0, 0.0)]);
+
+        // Two length-1 vectors in each buffer
+        x_complex_f64.axpy_strided_batch(1, Complex::new(2.0, 0.0), 1, 1, &mut y_complex_f64, 1, 1, 2);
+        assert_eq!(y_complex_f64, vec![Complex::new(2.0, 0.0), Complex::new(1.0, 2.0)]);
+
+        // Interleaved: the two rows of a 2 x 3 column-major matrix
+        let mut y_rows: Vec<f64> = vec![0.0; 6];
+        [1.0; 6].axpy_strided_batch(3, 1.0, 2, 1, &mut y_rows, 2, 1, 2);
+        assert_eq!(y_rows, vec![1.0; 6]);
+
+        // Neither separated nor interleaved: y_0 at 0, 3, 6 and y_1 at 4, 7, 10
+        let mut y_gapped: Vec<f64> = vec![0.0; 11];
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0].axpy_strided_batch(3, 1.0, 1, 3, &mut y_gapped, 3, 4, 2);
+        assert_eq!(y_gapped, vec![1.0, 0.0, 0.0, 2.0, 4.0, 0.0, 3.0, 5.0, 0.0, 0.0, 6.0]);
+    }
+}
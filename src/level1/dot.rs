@@ -1,5 +1,6 @@
 use num_complex::Complex;
 use rayon::prelude::*;
+use crate::modular::Modular;
 
 pub trait Dot {
     type Output;
@@ -66,6 +67,41 @@ impl Dot for [Complex<f64>] {
         .sum()
     }
 }
+// Products of i8 are widened to i32 before they are summed, as in quantized inference.
+// Each product is at most 128 * 128 = 2^14 in magnitude, so the sum cannot overflow for
+// vectors of fewer than 2^17 elements; longer ones must be split by the caller.
+impl Dot for [i8] {
+    type Output = i32;
+    fn dot(&self, incx: usize, y: &Self, incy: usize) -> Self::Output {
+        self
+        .par_iter()
+        .step_by(incx)
+        .zip(
+            y
+            .par_iter()
+            .step_by(incy)
+        )
+        .map(|(&x, &y)| x as i32 * y as i32)
+        .sum()
+    }
+}
+// Raw products are summed in a u128 and reduced once at the end.
+impl<const P: u64> Dot for [Modular<P>] {
+    type Output = Modular<P>;
+    fn dot(&self, incx: usize, y: &Self, incy: usize) -> Self::Output {
+        let acc = self
+        .par_iter()
+        .step_by(incx)
+        .zip(
+            y
+            .par_iter()
+            .step_by(incy)
+        )
+        .map(|(&x, &y)| x.raw_mul(y))
+        .sum();
+        Modular::reduce(acc)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -82,10 +118,17 @@ mod tests {
         let y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 2];
         let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
         let y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 2];
+        let x_i8: Vec<i8> = vec![127, -128, 100, 1];
+        let y_i8: Vec<i8> = vec![127, -128, 100, -1];
+        let x_modular: Vec<Modular<4294967291>> = vec![Modular::new(4294967290); 3];
+        let y_modular: Vec<Modular<4294967291>> = vec![Modular::new(4294967290); 3];
 
         assert_eq!(x_f32.as_slice().dot(1, &y_f32, 1), 30.0);
         assert_eq!(x_f64.as_slice().dot(2, &y_f64, 2), 18.0);
         assert_eq!(x_complex_f32.as_slice().dot(1, &y_complex_f32, 1), Complex::new(0.0, 0.0));
         assert_eq!(x_complex_f64.as_slice().dot(1, &y_complex_f64, 1), Complex::new(0.0, 0.0));
+        assert_eq!(x_i8.as_slice().dot(1, &y_i8, 1), 16129 + 16384 + 10000 - 1);
+        // (-1) * (-1) three times
+        assert_eq!(x_modular.as_slice().dot(1, &y_modular, 1), Modular::new(3));
     }
 }
\ No newline at end of file
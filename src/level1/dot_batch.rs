@@ -0,0 +1,127 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+
+pub trait DotBatch {
+    type Output;
+    fn dot_batch(&self, incx: usize, y: &Self, incy: usize) -> Vec<Self::Output>;
+}
+
+pub trait DotStridedBatch {
+    type Output;
+    fn dot_strided_batch(&self, n: usize, incx: usize, stridex: usize, y: &Self, incy: usize, stridey: usize, batch_count: usize) -> Vec<Self::Output>;
+}
+
+impl DotBatch for [&[f32]] {
+    type Output = f32;
+    fn dot_batch(&self, incx: usize, y: &Self, incy: usize) -> Vec<f32> {
+        dot_batch(self, incx, y, incy)
+    }
+}
+impl DotBatch for [&[f64]] {
+    type Output = f64;
+    fn dot_batch(&self, incx: usize, y: &Self, incy: usize) -> Vec<f64> {
+        dot_batch(self, incx, y, incy)
+    }
+}
+impl DotBatch for [&[Complex<f32>]] {
+    type Output = Complex<f32>;
+    fn dot_batch(&self, incx: usize, y: &Self, incy: usize) -> Vec<Complex<f32>> {
+        dot_batch(self, incx, y, incy)
+    }
+}
+impl DotBatch for [&[Complex<f64>]] {
+    type Output = Complex<f64>;
+    fn dot_batch(&self, incx: usize, y: &Self, incy: usize) -> Vec<Complex<f64>> {
+        dot_batch(self, incx, y, incy)
+    }
+}
+
+impl DotStridedBatch for [f32] {
+    type Output = f32;
+    fn dot_strided_batch(&self, n: usize, incx: usize, stridex: usize, y: &Self, incy: usize, stridey: usize, batch_count: usize) -> Vec<f32> {
+        dot_strided_batch(self, n, incx, stridex, y, incy, stridey, batch_count)
+    }
+}
+impl DotStridedBatch for [f64] {
+    type Output = f64;
+    fn dot_strided_batch(&self, n: usize, incx: usize, stridex: usize, y: &Self, incy: usize, stridey: usize, batch_count: usize) -> Vec<f64> {
+        dot_strided_batch(self, n, incx, stridex, y, incy, stridey, batch_count)
+    }
+}
+impl DotStridedBatch for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn dot_strided_batch(&self, n: usize, incx: usize, stridex: usize, y: &Self, incy: usize, stridey: usize, batch_count: usize) -> Vec<Complex<f32>> {
+        dot_strided_batch(self, n, incx, stridex, y, incy, stridey, batch_count)
+    }
+}
+impl DotStridedBatch for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn dot_strided_batch(&self, n: usize, incx: usize, stridex: usize, y: &Self, incy: usize, stridey: usize, batch_count: usize) -> Vec<Complex<f64>> {
+        dot_strided_batch(self, n, incx, stridex, y, incy, stridey, batch_count)
+    }
+}
+
+// One dot product per pair x[i], y[i]. The pairs are spread over the thread pool and each
+// product is computed on a single thread.
+fn dot_batch<T: Scalar>(x: &[&[T]], incx: usize, y: &[&[T]], incy: usize) -> Vec<T> {
+    assert_eq!(x.len(), y.len(), "x and y must hold the same number of vectors");
+    x
+    .par_iter()
+    .zip(y)
+    .map(|(x, y)| dot(x, incx, y, incy, usize::MAX))
+    .collect()
+}
+
+// One dot product of length n per i < batch_count, between the vectors starting at
+// x[i * stridex] and y[i * stridey]. A stride of zero reuses one vector for every product.
+fn dot_strided_batch<T: Scalar>(x: &[T], n: usize, incx: usize, stridex: usize, y: &[T], incy: usize, stridey: usize, batch_count: usize) -> Vec<T> {
+    (0..batch_count)
+    .into_par_iter()
+    .map(|i| dot(&x[i * stridex..], incx, &y[i * stridey..], incy, n))
+    .collect()
+}
+
+fn dot<T: Scalar>(x: &[T], incx: usize, y: &[T], incy: usize, n: usize) -> T {
+    x
+    .iter()
+    .step_by(incx)
+    .zip(
+        y
+        .iter()
+        .step_by(incy)
+    )
+    .take(n)
+    .map(|(&x, &y)| x * y)
+    .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_dot_batch() {
+        let x0_f32: Vec<f32> = vec![1.0, 2.0, 3.0];
+        let x1_f32: Vec<f32> = vec![4.0, 5.0];
+        let y0_f32: Vec<f32> = vec![2.0; 3];
+        let y1_f32: Vec<f32> = vec![1.0, -1.0];
+        // Three rows of a 3 x 2 column-major matrix against one query vector
+        let x_f64: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let y_f64: Vec<f64> = vec![1.0, 10.0];
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 1.0)];
+        let y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, -1.0), Complex::new(0.0, 1.0)];
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 1.0), Complex::new(2.0, 0.0), Complex::new(1.0, 0.0)];
+        let y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+
+        assert_eq!([x0_f32.as_slice(), &x1_f32].dot_batch(1, &[&y0_f32, &y1_f32], 1), vec![12.0, -1.0]);
+
+        assert_eq!(x_f64.dot_strided_batch(2, 3, 1, &y_f64, 1, 0, 3), vec![41.0, 52.0, 63.0]);
+
+        assert_eq!([x_complex_f32.as_slice()].dot_batch(1, &[&y_complex_f32], 1), vec![Complex::new(1.0, 0.0)]);
+
+        // Two contiguous vectors in x against a shared y
+        assert_eq!(x_complex_f64.dot_strided_batch(2, 1, 2, &y_complex_f64, 1, 0, 2), vec![Complex::new(-1.0, 3.0), Complex::new(2.0, 2.0)]);
+    }
+}
@@ -82,9 +82,9 @@ mod tests {
         let vector_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
         let vector_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
 
-        assert_eq!(vector_f32.as_slice().iamax(1), 4 as usize);
-        assert_eq!(vector_f64.as_slice().iamax(3), 3 as usize);
-        assert_eq!(vector_complex_f32.as_slice().iamax(1), 1 as usize);
-        assert_eq!(vector_complex_f64.as_slice().iamax(1), 1 as usize);
+        assert_eq!(vector_f32.as_slice().iamax(1), 4_usize);
+        assert_eq!(vector_f64.as_slice().iamax(3), 3_usize);
+        assert_eq!(vector_complex_f32.as_slice().iamax(1), 1_usize);
+        assert_eq!(vector_complex_f64.as_slice().iamax(1), 1_usize);
     }
 }
\ No newline at end of file
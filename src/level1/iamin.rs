@@ -82,9 +82,9 @@ mod tests {
         let vector_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
         let vector_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
 
-        assert_eq!(vector_f32.as_slice().iamin(1), 0 as usize);
-        assert_eq!(vector_f64.as_slice().iamin(3), 0 as usize);
-        assert_eq!(vector_complex_f32.as_slice().iamin(1), 0 as usize);
-        assert_eq!(vector_complex_f64.as_slice().iamin(1), 0 as usize);
+        assert_eq!(vector_f32.as_slice().iamin(1), 0_usize);
+        assert_eq!(vector_f64.as_slice().iamin(3), 0_usize);
+        assert_eq!(vector_complex_f32.as_slice().iamin(1), 0_usize);
+        assert_eq!(vector_complex_f64.as_slice().iamin(1), 0_usize);
     }
 }
\ No newline at end of file
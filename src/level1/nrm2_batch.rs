@@ -0,0 +1,115 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::{Real, Scalar};
+
+pub trait Nrm2Batch {
+    type Output;
+    fn nrm2_batch(&self, incx: usize) -> Vec<Self::Output>;
+}
+
+pub trait Nrm2StridedBatch {
+    type Output;
+    fn nrm2_strided_batch(&self, n: usize, incx: usize, stridex: usize, batch_count: usize) -> Vec<Self::Output>;
+}
+
+impl Nrm2Batch for [&[f32]] {
+    type Output = f32;
+    fn nrm2_batch(&self, incx: usize) -> Vec<f32> {
+        nrm2_batch(self, incx)
+    }
+}
+impl Nrm2Batch for [&[f64]] {
+    type Output = f64;
+    fn nrm2_batch(&self, incx: usize) -> Vec<f64> {
+        nrm2_batch(self, incx)
+    }
+}
+impl Nrm2Batch for [&[Complex<f32>]] {
+    type Output = f32;
+    fn nrm2_batch(&self, incx: usize) -> Vec<f32> {
+        nrm2_batch(self, incx)
+    }
+}
+impl Nrm2Batch for [&[Complex<f64>]] {
+    type Output = f64;
+    fn nrm2_batch(&self, incx: usize) -> Vec<f64> {
+        nrm2_batch(self, incx)
+    }
+}
+
+impl Nrm2StridedBatch for [f32] {
+    type Output = f32;
+    fn nrm2_strided_batch(&self, n: usize, incx: usize, stridex: usize, batch_count: usize) -> Vec<f32> {
+        nrm2_strided_batch(self, n, incx, stridex, batch_count)
+    }
+}
+impl Nrm2StridedBatch for [f64] {
+    type Output = f64;
+    fn nrm2_strided_batch(&self, n: usize, incx: usize, stridex: usize, batch_count: usize) -> Vec<f64> {
+        nrm2_strided_batch(self, n, incx, stridex, batch_count)
+    }
+}
+impl Nrm2StridedBatch for [Complex<f32>] {
+    type Output = f32;
+    fn nrm2_strided_batch(&self, n: usize, incx: usize, stridex: usize, batch_count: usize) -> Vec<f32> {
+        nrm2_strided_batch(self, n, incx, stridex, batch_count)
+    }
+}
+impl Nrm2StridedBatch for [Complex<f64>] {
+    type Output = f64;
+    fn nrm2_strided_batch(&self, n: usize, incx: usize, stridex: usize, batch_count: usize) -> Vec<f64> {
+        nrm2_strided_batch(self, n, incx, stridex, batch_count)
+    }
+}
+
+// The Euclidean norm of each x[i], one vector per task.
+fn nrm2_batch<T: Scalar>(x: &[&[T]], incx: usize) -> Vec<T::Real> {
+    x
+    .par_iter()
+    .map(|x| nrm2(x, incx, usize::MAX))
+    .collect()
+}
+
+// The Euclidean norm of the length-n vector starting at x[i * stridex], for i < batch_count.
+fn nrm2_strided_batch<T: Scalar>(x: &[T], n: usize, incx: usize, stridex: usize, batch_count: usize) -> Vec<T::Real> {
+    (0..batch_count)
+    .into_par_iter()
+    .map(|i| nrm2(&x[i * stridex..], incx, n))
+    .collect()
+}
+
+fn nrm2<T: Scalar>(x: &[T], incx: usize, n: usize) -> T::Real {
+    x
+    .iter()
+    .step_by(incx)
+    .take(n)
+    .map(|&x| x.conj() * x)
+    .sum::<T>()
+    .re()
+    .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_nrm2_batch() {
+        let x0_f32: Vec<f32> = vec![3.0, 4.0];
+        let x1_f32: Vec<f32> = vec![1.0, -9.0, 2.0, -9.0, 2.0];
+        // Columns of a 2 x 3 matrix
+        let x_f64: Vec<f64> = vec![3.0, 4.0, 0.0, 5.0, 6.0, 8.0];
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(3.0, 4.0), Complex::new(0.0, 0.0)];
+        // Rows of a 2 x 2 matrix
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 2.0), Complex::new(0.0, 3.0), Complex::new(2.0, 4.0), Complex::new(4.0, 0.0)];
+
+        assert_eq!([x0_f32.as_slice(), &x1_f32].nrm2_batch(2), vec![3.0, 3.0]);
+
+        assert_eq!(x_f64.nrm2_strided_batch(2, 1, 2, 3), vec![5.0, 5.0, 10.0]);
+
+        assert_eq!([x_complex_f32.as_slice()].nrm2_batch(1), vec![5.0]);
+
+        assert_eq!(x_complex_f64.nrm2_strided_batch(2, 2, 1, 2), vec![5.0, 5.0]);
+    }
+}
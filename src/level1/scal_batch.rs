@@ -0,0 +1,150 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::level1::axpy_batch::overlapping;
+use crate::scalar::Scalar;
+
+pub trait ScalBatch {
+    type Output;
+    fn scal_batch(&mut self, a: &[Self::Output], incx: usize);
+}
+
+pub trait ScalStridedBatch {
+    type Output;
+    fn scal_strided_batch(&mut self, n: usize, a: Self::Output, incx: usize, stridex: usize, batch_count: usize);
+}
+
+impl ScalBatch for [&mut [f32]] {
+    type Output = f32;
+    fn scal_batch(&mut self, a: &[f32], incx: usize) {
+        scal_batch(self, a, incx)
+    }
+}
+impl ScalBatch for [&mut [f64]] {
+    type Output = f64;
+    fn scal_batch(&mut self, a: &[f64], incx: usize) {
+        scal_batch(self, a, incx)
+    }
+}
+impl ScalBatch for [&mut [Complex<f32>]] {
+    type Output = Complex<f32>;
+    fn scal_batch(&mut self, a: &[Complex<f32>], incx: usize) {
+        scal_batch(self, a, incx)
+    }
+}
+impl ScalBatch for [&mut [Complex<f64>]] {
+    type Output = Complex<f64>;
+    fn scal_batch(&mut self, a: &[Complex<f64>], incx: usize) {
+        scal_batch(self, a, incx)
+    }
+}
+
+impl ScalStridedBatch for [f32] {
+    type Output = f32;
+    fn scal_strided_batch(&mut self, n: usize, a: f32, incx: usize, stridex: usize, batch_count: usize) {
+        scal_strided_batch(self, n, a, incx, stridex, batch_count)
+    }
+}
+impl ScalStridedBatch for [f64] {
+    type Output = f64;
+    fn scal_strided_batch(&mut self, n: usize, a: f64, incx: usize, stridex: usize, batch_count: usize) {
+        scal_strided_batch(self, n, a, incx, stridex, batch_count)
+    }
+}
+impl ScalStridedBatch for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn scal_strided_batch(&mut self, n: usize, a: Complex<f32>, incx: usize, stridex: usize, batch_count: usize) {
+        scal_strided_batch(self, n, a, incx, stridex, batch_count)
+    }
+}
+impl ScalStridedBatch for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn scal_strided_batch(&mut self, n: usize, a: Complex<f64>, incx: usize, stridex: usize, batch_count: usize) {
+        scal_strided_batch(self, n, a, incx, stridex, batch_count)
+    }
+}
+
+// x[i] *= a[i] for every i, one vector per task.
+fn scal_batch<T: Scalar>(x: &mut [&mut [T]], a: &[T], incx: usize) {
+    assert_eq!(a.len(), x.len(), "a must hold one scalar for every vector");
+    x
+    .par_iter_mut()
+    .zip(a)
+    .for_each(|(x, &a)| scal(x, a, incx, usize::MAX))
+}
+
+// x_i *= a for the length-n vector x_i starting at x[i * stridex], for i < batch_count. The
+// x_i must not overlap, but may interleave.
+fn scal_strided_batch<T: Scalar>(x: &mut [T], n: usize, a: T, incx: usize, stridex: usize, batch_count: usize) {
+    if batch_count == 0 || n == 0 {
+        return;
+    }
+    let span = (n - 1) * incx + 1;
+    if batch_count == 1 || stridex >= span {
+        // Each x_i lies within its own stride, so the vectors are scaled in parallel.
+        x
+        .par_chunks_mut(stridex.max(span))
+        .take(batch_count)
+        .for_each(|x| scal(x, a, incx, n))
+    } else if stridex > 0 && incx > (batch_count - 1) * stridex {
+        // Interleaved vectors: element r of every x_i lies within one increment, so the
+        // elements are scaled in parallel instead.
+        x
+        .par_chunks_mut(incx)
+        .take(n)
+        .for_each(|x| scal(x, a, stridex, batch_count))
+    } else {
+        // Neither separated nor fully interleaved: disjoint vectors are scaled in turn.
+        assert!(!overlapping(n, incx, stridex, batch_count), "the x vectors must not overlap");
+        for i in 0..batch_count {
+            scal(&mut x[i * stridex..], a, incx, n);
+        }
+    }
+}
+
+fn scal<T: Scalar>(x: &mut [T], a: T, incx: usize, n: usize) {
+    x
+    .iter_mut()
+    .step_by(incx)
+    .take(n)
+    .for_each(|x| *x *= a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_scal_batch() {
+        let mut x0_f32: Vec<f32> = vec![1.0, 2.0, 3.0];
+        let mut x1_f32: Vec<f32> = vec![4.0];
+        // The first row of each column of a 2 x 3 matrix
+        let mut x_f64: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 1.0)];
+        let mut x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+
+        [x0_f32.as_mut_slice(), &mut x1_f32].scal_batch(&[2.0, 0.5], 2);
+        assert_eq!(x0_f32, vec![2.0, 2.0, 6.0]);
+        assert_eq!(x1_f32, vec![2.0]);
+
+        x_f64.scal_strided_batch(1, -1.0, 1, 2, 3);
+        assert_eq!(x_f64, vec![-1.0, 2.0, -3.0, 4.0, -5.0, 6.0]);
+
+        [x_complex_f32.as_mut_slice()].scal_batch(&[Complex::new(0.0, 1.0)], 1);
+        assert_eq!(x_complex_f32, vec![Complex::new(-1.0, 1.0), Complex::new(-1.0, 0.0)]);
+
+        // Only the first two length-1 vectors are in the batch
+        x_complex_f64.scal_strided_batch(1, Complex::new(0.0, 2.0), 1, 1, 2);
+        assert_eq!(x_complex_f64, vec![Complex::new(0.0, 2.0), Complex::new(-2.0, 0.0), Complex::new(2.0, 0.0)]);
+
+        // Interleaved: the two rows of a 2 x 3 column-major matrix
+        let mut x_rows: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        x_rows.scal_strided_batch(3, 2.0, 2, 1, 2);
+        assert_eq!(x_rows, vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+
+        // Neither separated nor interleaved: x_0 at 0, 3, 6 and x_1 at 4, 7, 10
+        let mut x_gapped: Vec<f64> = vec![1.0; 11];
+        x_gapped.scal_strided_batch(3, 2.0, 3, 4, 2);
+        assert_eq!(x_gapped, vec![2.0, 1.0, 1.0, 2.0, 2.0, 1.0, 2.0, 2.0, 1.0, 1.0, 2.0]);
+    }
+}
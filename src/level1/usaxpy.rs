@@ -0,0 +1,79 @@
+use num_complex::Complex;
+use crate::scalar::Scalar;
+use crate::storage::sparse_vec::SparseVec;
+
+pub trait Usaxpy {
+    type Output;
+    fn usaxpy(&self, alpha: Self::Output, y: &mut [Self::Output], incy: usize);
+}
+
+impl Usaxpy for SparseVec<f32> {
+    type Output = f32;
+    fn usaxpy(&self, alpha: f32, y: &mut [f32], incy: usize) {
+        usaxpy(self, alpha, y, incy)
+    }
+}
+impl Usaxpy for SparseVec<f64> {
+    type Output = f64;
+    fn usaxpy(&self, alpha: f64, y: &mut [f64], incy: usize) {
+        usaxpy(self, alpha, y, incy)
+    }
+}
+impl Usaxpy for SparseVec<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn usaxpy(&self, alpha: Complex<f32>, y: &mut [Complex<f32>], incy: usize) {
+        usaxpy(self, alpha, y, incy)
+    }
+}
+impl Usaxpy for SparseVec<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn usaxpy(&self, alpha: Complex<f64>, y: &mut [Complex<f64>], incy: usize) {
+        usaxpy(self, alpha, y, incy)
+    }
+}
+
+// y[indices[k]] += alpha * x[k], with the stored elements split across threads.
+fn usaxpy<T: Scalar>(x: &SparseVec<T>, alpha: T, y: &mut [T], incy: usize) {
+    if alpha == T::zero() {
+        return;
+    }
+    x.par_scatter(y, incy, |y, v| *y += alpha * v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_usaxpy() {
+        let x_f32: SparseVec<f32> = SparseVec::new(4, vec![0, 3], vec![1.0, 2.0]);
+        let mut y_f32: Vec<f32> = vec![1.0; 4];
+        let x_f64: SparseVec<f64> = SparseVec::new(3, vec![1], vec![2.0]);
+        let mut y_f64: Vec<f64> = vec![1.0; 5];
+        let x_complex_f32: SparseVec<Complex<f32>> = SparseVec::new(2, vec![1], vec![Complex::new(1.0, 2.0)]);
+        let mut y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0); 2];
+        let x_complex_f64: SparseVec<Complex<f64>> = SparseVec::new(2, vec![0], vec![Complex::new(1.0, 2.0)]);
+        let mut y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(f64::NAN, 0.0), Complex::new(1.0, 0.0)];
+
+        x_f32.usaxpy(2.0, &mut y_f32, 1);
+        assert_eq!(y_f32, vec![3.0, 1.0, 1.0, 5.0]);
+
+        x_f64.usaxpy(3.0, &mut y_f64, 2);
+        assert_eq!(y_f64, vec![1.0, 1.0, 7.0, 1.0, 1.0]);
+
+        x_complex_f32.usaxpy(Complex::new(0.0, 1.0), &mut y_complex_f32, 1);
+        assert_eq!(y_complex_f32, vec![Complex::new(1.0, 0.0), Complex::new(-1.0, 1.0)]);
+
+        // Alpha of zero leaves y untouched
+        x_complex_f64.usaxpy(Complex::new(0.0, 0.0), &mut y_complex_f64, 1);
+        assert!(y_complex_f64[0].re.is_nan());
+
+        // Enough stored elements to split across threads
+        let n = 100_000;
+        let x = SparseVec::new(n, (0..n).step_by(3).collect(), (0..n).step_by(3).map(|i| i as f64).collect());
+        let mut y: Vec<f64> = vec![1.0; 2 * n];
+        x.usaxpy(2.0, &mut y, 2);
+        assert!(y.iter().enumerate().all(|(k, &y)| y == if k % 6 == 0 { k as f64 + 1.0 } else { 1.0 }));
+    }
+}
@@ -0,0 +1,68 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::storage::sparse_vec::SparseVec;
+
+pub trait Usdot {
+    type Output;
+    fn usdot(&self, conj: bool, y: &[Self::Output], incy: usize) -> Self::Output;
+}
+
+impl Usdot for SparseVec<f32> {
+    type Output = f32;
+    fn usdot(&self, conj: bool, y: &[f32], incy: usize) -> f32 {
+        usdot(self, conj, y, incy)
+    }
+}
+impl Usdot for SparseVec<f64> {
+    type Output = f64;
+    fn usdot(&self, conj: bool, y: &[f64], incy: usize) -> f64 {
+        usdot(self, conj, y, incy)
+    }
+}
+impl Usdot for SparseVec<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn usdot(&self, conj: bool, y: &[Complex<f32>], incy: usize) -> Complex<f32> {
+        usdot(self, conj, y, incy)
+    }
+}
+impl Usdot for SparseVec<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn usdot(&self, conj: bool, y: &[Complex<f64>], incy: usize) -> Complex<f64> {
+        usdot(self, conj, y, incy)
+    }
+}
+
+// Sum of x[k] * y[indices[k]] over the stored elements of x, conjugating x when `conj` is set.
+fn usdot<T: Scalar>(x: &SparseVec<T>, conj: bool, y: &[T], incy: usize) -> T {
+    x
+    .indices()
+    .par_iter()
+    .zip(x.values())
+    .map(|(&i, &v)| if conj { v.conj() } else { v } * y[i * incy])
+    .reduce(T::zero, |a, b| a + b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_usdot() {
+        let x_f32: SparseVec<f32> = SparseVec::new(5, vec![0, 3], vec![1.0, 2.0]);
+        let y_f32: Vec<f32> = vec![3.0, 9.0, 9.0, 4.0, 9.0];
+        let x_f64: SparseVec<f64> = SparseVec::new(3, vec![1, 2], vec![1.0, 2.0]);
+        let y_f64: Vec<f64> = vec![9.0, 0.0, 3.0, 0.0, 4.0];
+        let x_complex_f32: SparseVec<Complex<f32>> = SparseVec::new(4, vec![2], vec![Complex::new(1.0, 2.0)]);
+        let y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(9.0, 9.0), Complex::new(9.0, 9.0), Complex::new(3.0, 4.0), Complex::new(9.0, 9.0)];
+        let x_complex_f64: SparseVec<Complex<f64>> = SparseVec::new(4, vec![2], vec![Complex::new(1.0, 2.0)]);
+        let y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(9.0, 9.0), Complex::new(9.0, 9.0), Complex::new(3.0, 4.0), Complex::new(9.0, 9.0)];
+
+        assert_eq!(x_f32.usdot(false, &y_f32, 1), 11.0);
+        assert_eq!(x_f64.usdot(false, &y_f64, 2), 11.0);
+        assert_eq!(x_complex_f32.usdot(false, &y_complex_f32, 1), Complex::new(-5.0, 10.0));
+        assert_eq!(x_complex_f64.usdot(true, &y_complex_f64, 1), Complex::new(11.0, -2.0));
+        assert_eq!(SparseVec::<f64>::zeros(5).usdot(false, &y_f64, 1), 0.0);
+    }
+}
@@ -0,0 +1,73 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::storage::sparse_vec::SparseVec;
+
+pub trait Usga {
+    type Output;
+    fn usga(&mut self, y: &[Self::Output], incy: usize);
+}
+
+impl Usga for SparseVec<f32> {
+    type Output = f32;
+    fn usga(&mut self, y: &[f32], incy: usize) {
+        usga(self, y, incy)
+    }
+}
+impl Usga for SparseVec<f64> {
+    type Output = f64;
+    fn usga(&mut self, y: &[f64], incy: usize) {
+        usga(self, y, incy)
+    }
+}
+impl Usga for SparseVec<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn usga(&mut self, y: &[Complex<f32>], incy: usize) {
+        usga(self, y, incy)
+    }
+}
+impl Usga for SparseVec<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn usga(&mut self, y: &[Complex<f64>], incy: usize) {
+        usga(self, y, incy)
+    }
+}
+
+// x[k] = y[indices[k]]: fills the values of x from the dense vector at its stored indices.
+pub(crate) fn usga<T: Scalar>(x: &mut SparseVec<T>, y: &[T], incy: usize) {
+    let (indices, values) = x.parts_mut();
+    values
+    .par_iter_mut()
+    .zip(indices)
+    .for_each(|(v, &i)| *v = y[i * incy]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_usga() {
+        let mut x_f32: SparseVec<f32> = SparseVec::new(4, vec![0, 3], vec![0.0; 2]);
+        let y_f32: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0];
+        let mut x_f64: SparseVec<f64> = SparseVec::new(3, vec![1, 2], vec![0.0; 2]);
+        let y_f64: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut x_complex_f32: SparseVec<Complex<f32>> = SparseVec::new(2, vec![1], vec![Complex::new(0.0, 0.0)]);
+        let y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut x_complex_f64: SparseVec<Complex<f64>> = SparseVec::new(2, vec![0], vec![Complex::new(0.0, 0.0)]);
+        let y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+
+        x_f32.usga(&y_f32, 1);
+        assert_eq!(x_f32.values(), &[1.0, 4.0]);
+
+        x_f64.usga(&y_f64, 2);
+        assert_eq!(x_f64.values(), &[3.0, 5.0]);
+
+        x_complex_f32.usga(&y_complex_f32, 1);
+        assert_eq!(x_complex_f32.values(), &[Complex::new(0.0, 1.0)]);
+
+        x_complex_f64.usga(&y_complex_f64, 1);
+        assert_eq!(x_complex_f64.values(), &[Complex::new(1.0, 0.0)]);
+    }
+}
@@ -0,0 +1,74 @@
+use num_complex::Complex;
+use crate::level1::usga::usga;
+use crate::scalar::Scalar;
+use crate::storage::sparse_vec::SparseVec;
+
+pub trait Usgz {
+    type Output;
+    fn usgz(&mut self, y: &mut [Self::Output], incy: usize);
+}
+
+impl Usgz for SparseVec<f32> {
+    type Output = f32;
+    fn usgz(&mut self, y: &mut [f32], incy: usize) {
+        usgz(self, y, incy)
+    }
+}
+impl Usgz for SparseVec<f64> {
+    type Output = f64;
+    fn usgz(&mut self, y: &mut [f64], incy: usize) {
+        usgz(self, y, incy)
+    }
+}
+impl Usgz for SparseVec<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn usgz(&mut self, y: &mut [Complex<f32>], incy: usize) {
+        usgz(self, y, incy)
+    }
+}
+impl Usgz for SparseVec<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn usgz(&mut self, y: &mut [Complex<f64>], incy: usize) {
+        usgz(self, y, incy)
+    }
+}
+
+// Gathers y into x as usga does, then zeroes the gathered elements of y.
+fn usgz<T: Scalar>(x: &mut SparseVec<T>, y: &mut [T], incy: usize) {
+    usga(x, y, incy);
+    x.par_scatter(y, incy, |y, _| *y = T::zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_usgz() {
+        let mut x_f32: SparseVec<f32> = SparseVec::new(4, vec![0, 3], vec![0.0; 2]);
+        let mut y_f32: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0];
+        let mut x_f64: SparseVec<f64> = SparseVec::new(3, vec![1, 2], vec![0.0; 2]);
+        let mut y_f64: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut x_complex_f32: SparseVec<Complex<f32>> = SparseVec::new(2, vec![1], vec![Complex::new(0.0, 0.0)]);
+        let mut y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut x_complex_f64: SparseVec<Complex<f64>> = SparseVec::new(2, vec![0], vec![Complex::new(0.0, 0.0)]);
+        let mut y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+
+        x_f32.usgz(&mut y_f32, 1);
+        assert_eq!(x_f32.values(), &[1.0, 4.0]);
+        assert_eq!(y_f32, vec![0.0, 2.0, 3.0, 0.0]);
+
+        x_f64.usgz(&mut y_f64, 2);
+        assert_eq!(x_f64.values(), &[3.0, 5.0]);
+        assert_eq!(y_f64, vec![1.0, 2.0, 0.0, 4.0, 0.0]);
+
+        x_complex_f32.usgz(&mut y_complex_f32, 1);
+        assert_eq!(x_complex_f32.values(), &[Complex::new(0.0, 1.0)]);
+        assert_eq!(y_complex_f32, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
+
+        x_complex_f64.usgz(&mut y_complex_f64, 1);
+        assert_eq!(x_complex_f64.values(), &[Complex::new(1.0, 0.0)]);
+        assert_eq!(y_complex_f64, vec![Complex::new(0.0, 0.0), Complex::new(0.0, 1.0)]);
+    }
+}
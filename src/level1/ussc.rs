@@ -0,0 +1,79 @@
+use num_complex::Complex;
+use crate::scalar::Scalar;
+use crate::storage::sparse_vec::SparseVec;
+
+pub trait Ussc {
+    type Output;
+    fn ussc(&self, y: &mut [Self::Output], incy: usize);
+}
+
+impl Ussc for SparseVec<f32> {
+    type Output = f32;
+    fn ussc(&self, y: &mut [f32], incy: usize) {
+        ussc(self, y, incy)
+    }
+}
+impl Ussc for SparseVec<f64> {
+    type Output = f64;
+    fn ussc(&self, y: &mut [f64], incy: usize) {
+        ussc(self, y, incy)
+    }
+}
+impl Ussc for SparseVec<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn ussc(&self, y: &mut [Complex<f32>], incy: usize) {
+        ussc(self, y, incy)
+    }
+}
+impl Ussc for SparseVec<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn ussc(&self, y: &mut [Complex<f64>], incy: usize) {
+        ussc(self, y, incy)
+    }
+}
+
+// y[indices[k]] = x[k], with the stored elements split across threads; elements of y outside
+// the pattern of x are left as they are.
+fn ussc<T: Scalar>(x: &SparseVec<T>, y: &mut [T], incy: usize) {
+    x.par_scatter(y, incy, |y, v| *y = v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level1::usgz::Usgz;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_ussc() {
+        let x_f32: SparseVec<f32> = SparseVec::new(4, vec![0, 3], vec![1.0, 2.0]);
+        let mut y_f32: Vec<f32> = vec![9.0; 4];
+        let x_f64: SparseVec<f64> = SparseVec::new(3, vec![1], vec![2.0]);
+        let mut y_f64: Vec<f64> = vec![0.0; 5];
+        let x_complex_f32: SparseVec<Complex<f32>> = SparseVec::new(2, vec![1], vec![Complex::new(1.0, 2.0)]);
+        let mut y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 2];
+        let mut x_complex_f64: SparseVec<Complex<f64>> = SparseVec::new(3, vec![0, 2], vec![Complex::new(0.0, 0.0); 2]);
+        let mut y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0)];
+
+        x_f32.ussc(&mut y_f32, 1);
+        assert_eq!(y_f32, vec![1.0, 9.0, 9.0, 2.0]);
+
+        x_f64.ussc(&mut y_f64, 2);
+        assert_eq!(y_f64, vec![0.0, 0.0, 2.0, 0.0, 0.0]);
+
+        x_complex_f32.ussc(&mut y_complex_f32, 1);
+        assert_eq!(y_complex_f32, vec![Complex::new(0.0, 0.0), Complex::new(1.0, 2.0)]);
+
+        // Gather-and-zero followed by scatter restores y
+        x_complex_f64.usgz(&mut y_complex_f64, 1);
+        x_complex_f64.ussc(&mut y_complex_f64, 1);
+        assert_eq!(y_complex_f64, vec![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0)]);
+
+        // Enough stored elements to split across threads, against the dense copy
+        let n = 100_000;
+        let x = SparseVec::new(n, (0..n).filter(|i| i % 7 != 0).collect(), (0..n).filter(|i| i % 7 != 0).map(|i| i as f64).collect());
+        let mut y: Vec<f64> = vec![0.0; n];
+        x.ussc(&mut y, 1);
+        assert_eq!(y, x.to_dense());
+    }
+}
@@ -0,0 +1,127 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+
+pub trait Waxpby {
+    type Output;
+    fn waxpby(&self, a: Self::Output, incx: usize, b: Self::Output, y: &Self, incy: usize, w: &mut Self, incw: usize);
+}
+
+impl Waxpby for [f32] {
+    type Output = f32;
+    fn waxpby(&self, a: f32, incx: usize, b: f32, y: &Self, incy: usize, w: &mut Self, incw: usize) {
+        self
+        .par_iter()
+        .step_by(incx)
+        .zip(
+            y
+            .par_iter()
+            .step_by(incy)
+        )
+        .zip(
+            w
+            .par_iter_mut()
+            .step_by(incw)
+        )
+        .for_each(|((x, y), w)| {
+            *w = a * *x + b * *y;
+        })
+    }
+}
+impl Waxpby for [f64] {
+    type Output = f64;
+    fn waxpby(&self, a: f64, incx: usize, b: f64, y: &Self, incy: usize, w: &mut Self, incw: usize) {
+        self
+        .par_iter()
+        .step_by(incx)
+        .zip(
+            y
+            .par_iter()
+            .step_by(incy)
+        )
+        .zip(
+            w
+            .par_iter_mut()
+            .step_by(incw)
+        )
+        .for_each(|((x, y), w)| {
+            *w = a * *x + b * *y;
+        })
+    }
+}
+impl Waxpby for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn waxpby(&self, a: Complex<f32>, incx: usize, b: Complex<f32>, y: &Self, incy: usize, w: &mut Self, incw: usize) {
+        self
+        .par_iter()
+        .step_by(incx)
+        .zip(
+            y
+            .par_iter()
+            .step_by(incy)
+        )
+        .zip(
+            w
+            .par_iter_mut()
+            .step_by(incw)
+        )
+        .for_each(|((x, y), w)| {
+            *w = a * *x + b * *y;
+        })
+    }
+}
+impl Waxpby for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn waxpby(&self, a: Complex<f64>, incx: usize, b: Complex<f64>, y: &Self, incy: usize, w: &mut Self, incw: usize) {
+        self
+        .par_iter()
+        .step_by(incx)
+        .zip(
+            y
+            .par_iter()
+            .step_by(incy)
+        )
+        .zip(
+            w
+            .par_iter_mut()
+            .step_by(incw)
+        )
+        .for_each(|((x, y), w)| {
+            *w = a * *x + b * *y;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_waxpby() {
+        let x_f32: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_f32: Vec<f32> = vec![2.0; 5];
+        let mut w_f32: Vec<f32> = vec![0.0; 5];
+        let x_f64 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_f64 = vec![2.0; 5];
+        let mut w_f64 = vec![-1.0; 3];
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+        let y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0); 2];
+        let mut w_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 2];
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+        let y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0); 2];
+        let mut w_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 2];
+
+        x_f32.as_slice().waxpby(2.0, 1, 3.0, &y_f32, 1, &mut w_f32, 1);
+        assert_eq!(w_f32, vec![8.0, 10.0, 12.0, 14.0, 16.0]);
+        assert_eq!(y_f32, vec![2.0; 5]);
+
+        x_f64.as_slice().waxpby(2.0, 2, 0.5, &y_f64, 2, &mut w_f64, 1);
+        assert_eq!(w_f64, vec![3.0, 7.0, 11.0]);
+
+        x_complex_f32.as_slice().waxpby(Complex::new(1.0, 0.0), 1, Complex::new(0.0, 1.0), &y_complex_f32, 1, &mut w_complex_f32, 1);
+        assert_eq!(w_complex_f32, vec![Complex::new(0.0, 3.0), Complex::new(2.0, 5.0)]);
+
+        x_complex_f64.as_slice().waxpby(Complex::new(1.0, 0.0), 1, Complex::new(0.0, 1.0), &y_complex_f64, 1, &mut w_complex_f64, 1);
+        assert_eq!(w_complex_f64, vec![Complex::new(0.0, 3.0), Complex::new(2.0, 5.0)]);
+    }
+}
@@ -0,0 +1,28 @@
+// Matrix-vector operations
+pub mod gemv;
+pub mod gemv_modular;
+pub mod gbmv;
+pub mod symv;
+pub mod hemv;
+pub mod spmv;
+pub mod hpmv;
+pub mod spr;
+pub mod hpr;
+pub mod spr2;
+pub mod hpr2;
+pub mod trmv;
+pub mod trsv;
+pub mod tbmv;
+pub mod tbsv;
+pub mod tpmv;
+pub mod tpsv;
+pub mod ger;
+pub mod geru;
+pub mod gerc;
+pub mod syr;
+pub mod her;
+pub mod syr2;
+pub mod her2;
+pub mod sbmv;
+pub mod hbmv;
+pub mod sparse_mv;
@@ -0,0 +1,104 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::storage::band::BandMatrix;
+use crate::types::Trans;
+
+pub trait Gbmv {
+    type Output;
+    fn gbmv(&self, trans: Trans, alpha: Self::Output, x: &[Self::Output], incx: usize, beta: Self::Output, y: &mut [Self::Output], incy: usize);
+}
+
+impl Gbmv for BandMatrix<f32> {
+    type Output = f32;
+    fn gbmv(&self, trans: Trans, alpha: f32, x: &[f32], incx: usize, beta: f32, y: &mut [f32], incy: usize) {
+        gbmv(self, trans, alpha, x, incx, beta, y, incy)
+    }
+}
+impl Gbmv for BandMatrix<f64> {
+    type Output = f64;
+    fn gbmv(&self, trans: Trans, alpha: f64, x: &[f64], incx: usize, beta: f64, y: &mut [f64], incy: usize) {
+        gbmv(self, trans, alpha, x, incx, beta, y, incy)
+    }
+}
+impl Gbmv for BandMatrix<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn gbmv(&self, trans: Trans, alpha: Complex<f32>, x: &[Complex<f32>], incx: usize, beta: Complex<f32>, y: &mut [Complex<f32>], incy: usize) {
+        gbmv(self, trans, alpha, x, incx, beta, y, incy)
+    }
+}
+impl Gbmv for BandMatrix<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn gbmv(&self, trans: Trans, alpha: Complex<f64>, x: &[Complex<f64>], incx: usize, beta: Complex<f64>, y: &mut [Complex<f64>], incy: usize) {
+        gbmv(self, trans, alpha, x, incx, beta, y, incy)
+    }
+}
+
+// y = alpha * op(A) * x + beta * y. Each element of y is an independent dot product over
+// the band, so the rows of op(A) are processed in parallel.
+fn gbmv<T: Scalar>(a: &BandMatrix<T>, trans: Trans, alpha: T, x: &[T], incx: usize, beta: T, y: &mut [T], incy: usize) {
+    let len = if trans == Trans::NoTrans { a.rows() } else { a.cols() };
+    y
+    .par_iter_mut()
+    .step_by(incy)
+    .take(len)
+    .enumerate()
+    .for_each(|(r, y)| {
+        let mut sum = T::zero();
+        if alpha != T::zero() {
+            match trans {
+                Trans::NoTrans => {
+                    for j in a.row_range(r) {
+                        sum += a.get(r, j) * x[j * incx];
+                    }
+                }
+                Trans::Trans => {
+                    for i in a.col_range(r) {
+                        sum += a.get(i, r) * x[i * incx];
+                    }
+                }
+                Trans::ConjTrans => {
+                    for i in a.col_range(r) {
+                        sum += a.get(i, r).conj() * x[i * incx];
+                    }
+                }
+            }
+        }
+        *y = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * *y };
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_gbmv() {
+        // 4 x 3 tridiagonal-like band: [[1, 3, 0], [2, 4, 6], [0, 5, 7], [0, 0, 8]]
+        let dense_f32: Vec<f32> = vec![1.0, 2.0, 0.0, 0.0, 3.0, 4.0, 5.0, 0.0, 0.0, 6.0, 7.0, 8.0];
+        let a_f32 = BandMatrix::from_dense(4, 3, 1, 1, &dense_f32, 4);
+        let mut y_f32: Vec<f32> = vec![1.0; 4];
+        let dense_f64: Vec<f64> = vec![1.0, 2.0, 0.0, 0.0, 3.0, 4.0, 5.0, 0.0, 0.0, 6.0, 7.0, 8.0];
+        let a_f64 = BandMatrix::from_dense(4, 3, 1, 1, &dense_f64, 4);
+        let mut y_f64: Vec<f64> = vec![1.0, 0.0, 1.0, 0.0, 1.0, 0.0];
+        // 2 x 2 upper bidiagonal: [[1+i, 2], [0, 3i]]
+        let a_complex_f32: BandMatrix<Complex<f32>> = BandMatrix::new(2, 2, 0, 1, vec![Complex::new(0.0, 0.0), Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0)]);
+        let mut y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 2];
+        let a_complex_f64: BandMatrix<Complex<f64>> = BandMatrix::new(2, 2, 0, 1, vec![Complex::new(0.0, 0.0), Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0)]);
+        let mut y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(f64::NAN, 0.0); 2];
+
+        a_f32.gbmv(Trans::NoTrans, 1.0, &[1.0, 1.0, 1.0], 1, 2.0, &mut y_f32, 1);
+        assert_eq!(y_f32, vec![6.0, 14.0, 14.0, 10.0]);
+
+        a_f64.gbmv(Trans::Trans, 2.0, &[1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0], 2, 1.0, &mut y_f64, 2);
+        assert_eq!(y_f64, vec![7.0, 0.0, 25.0, 0.0, 43.0, 0.0]);
+
+        a_complex_f32.gbmv(Trans::NoTrans, Complex::new(1.0, 0.0), &[Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)], 1, Complex::new(0.0, 0.0), &mut y_complex_f32, 1);
+        assert_eq!(y_complex_f32, vec![Complex::new(1.0, 3.0), Complex::new(-3.0, 0.0)]);
+
+        // Beta of zero overwrites y without reading it
+        a_complex_f64.gbmv(Trans::ConjTrans, Complex::new(1.0, 0.0), &[Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)], 1, Complex::new(0.0, 0.0), &mut y_complex_f64, 1);
+        assert_eq!(y_complex_f64, vec![Complex::new(1.0, -1.0), Complex::new(5.0, 0.0)]);
+    }
+}
@@ -0,0 +1,91 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::types::{op_at, Trans};
+
+pub trait Gemv {
+    type Output;
+    fn gemv(&self, trans: Trans, m: usize, n: usize, alpha: Self::Output, lda: usize, x: &Self, incx: usize, beta: Self::Output, y: &mut Self, incy: usize);
+}
+
+impl Gemv for [f32] {
+    type Output = f32;
+    fn gemv(&self, trans: Trans, m: usize, n: usize, alpha: f32, lda: usize, x: &Self, incx: usize, beta: f32, y: &mut Self, incy: usize) {
+        gemv(self, trans, m, n, alpha, lda, x, incx, beta, y, incy)
+    }
+}
+impl Gemv for [f64] {
+    type Output = f64;
+    fn gemv(&self, trans: Trans, m: usize, n: usize, alpha: f64, lda: usize, x: &Self, incx: usize, beta: f64, y: &mut Self, incy: usize) {
+        gemv(self, trans, m, n, alpha, lda, x, incx, beta, y, incy)
+    }
+}
+impl Gemv for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn gemv(&self, trans: Trans, m: usize, n: usize, alpha: Complex<f32>, lda: usize, x: &Self, incx: usize, beta: Complex<f32>, y: &mut Self, incy: usize) {
+        gemv(self, trans, m, n, alpha, lda, x, incx, beta, y, incy)
+    }
+}
+impl Gemv for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn gemv(&self, trans: Trans, m: usize, n: usize, alpha: Complex<f64>, lda: usize, x: &Self, incx: usize, beta: Complex<f64>, y: &mut Self, incy: usize) {
+        gemv(self, trans, m, n, alpha, lda, x, incx, beta, y, incy)
+    }
+}
+
+// y = alpha * op(A) * x + beta * y for an m x n A. Each element of y is an independent dot
+// product with a row of op(A), so they are computed in parallel.
+fn gemv<T: Scalar>(a: &[T], trans: Trans, m: usize, n: usize, alpha: T, lda: usize, x: &[T], incx: usize, beta: T, y: &mut [T], incy: usize) {
+    let (rows, cols) = if trans == Trans::NoTrans { (m, n) } else { (n, m) };
+    y
+    .par_iter_mut()
+    .step_by(incy)
+    .take(rows)
+    .enumerate()
+    .for_each(|(i, y)| {
+        let mut sum = T::zero();
+        if alpha != T::zero() {
+            for j in 0..cols {
+                sum += op_at(a, trans, lda, i, j) * x[j * incx];
+            }
+        }
+        *y = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * *y };
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_gemv() {
+        // A = [[1, 2, 3], [4, 5, 6]]
+        let a_f32: Vec<f32> = vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+        let x_f32: Vec<f32> = vec![1.0, 0.0, 1.0];
+        let mut y_f32: Vec<f32> = vec![1.0, 1.0];
+        let a_f64: Vec<f64> = vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+        let x_f64: Vec<f64> = vec![1.0, -9.0, 1.0];
+        let mut y_f64: Vec<f64> = vec![f64::NAN, -9.0, f64::NAN, -9.0, f64::NAN];
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 1.0), Complex::new(1.0, -1.0)];
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 2];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 1.0), Complex::new(1.0, -1.0)];
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0); 2];
+
+        a_f32.as_slice().gemv(Trans::NoTrans, 2, 3, 1.0, 2, &x_f32, 1, 2.0, &mut y_f32, 1);
+        assert_eq!(y_f32, vec![6.0, 12.0]);
+
+        // A^T * [1, 1], with y at stride 2
+        a_f64.as_slice().gemv(Trans::Trans, 2, 3, 1.0, 2, &x_f64, 2, 0.0, &mut y_f64, 2);
+        assert_eq!(y_f64, vec![5.0, -9.0, 7.0, -9.0, 9.0]);
+
+        a_complex_f32.as_slice().gemv(Trans::NoTrans, 2, 2, Complex::new(1.0, 0.0), 2, &x_complex_f32, 1, Complex::new(0.0, 0.0), &mut y_complex_f32, 1);
+        assert_eq!(y_complex_f32, vec![Complex::new(0.0, 1.0), Complex::new(3.0, 1.0)]);
+
+        a_complex_f64.as_slice().gemv(Trans::ConjTrans, 2, 2, Complex::new(1.0, 0.0), 2, &x_complex_f64, 1, Complex::new(1.0, 0.0), &mut y_complex_f64, 1);
+        assert_eq!(y_complex_f64, vec![Complex::new(2.0, 1.0), Complex::new(0.0, 0.0)]);
+
+    }
+}
@@ -0,0 +1,55 @@
+use rayon::prelude::*;
+use crate::level2::gemv::Gemv;
+use crate::modular::Modular;
+use crate::types::Trans;
+
+impl<const P: u64> Gemv for [Modular<P>] {
+    type Output = Modular<P>;
+    fn gemv(&self, trans: Trans, m: usize, n: usize, alpha: Modular<P>, lda: usize, x: &Self, incx: usize, beta: Modular<P>, y: &mut Self, incy: usize) {
+        gemv_modular(self, trans, m, n, alpha, lda, x, incx, beta, y, incy)
+    }
+}
+
+// y = alpha * op(A) * x + beta * y over the integers mod P, ConjTrans being Trans. As in the
+// float `gemv` each element of y is computed in parallel, with its raw products summed in a
+// u128 and reduced once.
+fn gemv_modular<const P: u64>(a: &[Modular<P>], trans: Trans, m: usize, n: usize, alpha: Modular<P>, lda: usize, x: &[Modular<P>], incx: usize, beta: Modular<P>, y: &mut [Modular<P>], incy: usize) {
+    let (rows, cols) = if trans == Trans::NoTrans { (m, n) } else { (n, m) };
+    y
+    .par_iter_mut()
+    .step_by(incy)
+    .take(rows)
+    .enumerate()
+    .for_each(|(i, y)| {
+        let mut acc = 0u128;
+        for j in 0..cols {
+            let a_ij = if trans == Trans::NoTrans { a[i + j * lda] } else { a[j + i * lda] };
+            acc += a_ij.raw_mul(x[j * incx]);
+        }
+        let ax = alpha * Modular::reduce(acc);
+        *y = if beta == Modular::zero() { ax } else { ax + beta * *y };
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gemv_modular() {
+        // Over GF(7): [[6, 6], [6, 6]] * [6, 6] = [72, 72] = [2, 2]
+        let a_modular: Vec<Modular<7>> = vec![Modular::new(6); 4];
+        let x_modular: Vec<Modular<7>> = vec![Modular::new(6); 2];
+        let mut y_modular: Vec<Modular<7>> = vec![Modular::new(1); 2];
+        a_modular.as_slice().gemv(Trans::NoTrans, 2, 2, Modular::new(1), 2, &x_modular, 1, Modular::new(3), &mut y_modular, 1);
+        assert_eq!(y_modular, vec![Modular::new(5); 2]);
+
+        // A = [[1, 2, 3], [4, 5, 6]] transposed against x = [1, P - 1], so A^T x = [-3, -3, -3]
+        const P: u64 = 4294967291;
+        let a: Vec<Modular<P>> = [1, 4, 2, 5, 3, 6].map(Modular::new).to_vec();
+        let x: Vec<Modular<P>> = vec![Modular::new(1), Modular::new(P - 1)];
+        let mut y: Vec<Modular<P>> = vec![Modular::new(9); 3];
+        a.as_slice().gemv(Trans::Trans, 2, 3, Modular::new(1), 2, &x, 1, Modular::new(0), &mut y, 1);
+        assert_eq!(y, vec![Modular::new(P - 3); 3]);
+    }
+}
@@ -0,0 +1,61 @@
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+
+pub trait Ger {
+    type Output;
+    fn ger(&self, m: usize, n: usize, alpha: Self::Output, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize);
+}
+
+impl Ger for [f32] {
+    type Output = f32;
+    fn ger(&self, m: usize, n: usize, alpha: f32, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize) {
+        ger(self, m, n, alpha, incx, y, incy, a, lda, false)
+    }
+}
+impl Ger for [f64] {
+    type Output = f64;
+    fn ger(&self, m: usize, n: usize, alpha: f64, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize) {
+        ger(self, m, n, alpha, incx, y, incy, a, lda, false)
+    }
+}
+
+// A = alpha * x * y^T + A (or x * y^H, when `conj`) for an m x n A, in parallel over columns.
+pub(crate) fn ger<T: Scalar>(x: &[T], m: usize, n: usize, alpha: T, incx: usize, y: &[T], incy: usize, a: &mut [T], lda: usize, conj: bool) {
+    if m == 0 || alpha == T::zero() {
+        return;
+    }
+    a
+    .par_chunks_mut(lda)
+    .take(n)
+    .enumerate()
+    .for_each(|(j, col)| {
+        let yj = y[j * incy];
+        let temp = alpha * if conj { yj.conj() } else { yj };
+        col[..m]
+        .iter_mut()
+        .zip(x.iter().step_by(incx))
+        .for_each(|(a, &x)| *a += x * temp);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ger() {
+        let x_f32: Vec<f32> = vec![1.0, 2.0];
+        let y_f32: Vec<f32> = vec![3.0, 4.0, 5.0];
+        let mut a_f32: Vec<f32> = vec![1.0; 6];
+        let x_f64: Vec<f64> = vec![1.0, 0.0, 2.0];
+        let y_f64: Vec<f64> = vec![3.0, 0.0, 4.0];
+        let mut a_f64: Vec<f64> = vec![0.0, 0.0, -1.0, 0.0, 0.0, -1.0];
+
+        x_f32.as_slice().ger(2, 3, 1.0, 1, &y_f32, 1, &mut a_f32, 2);
+        assert_eq!(a_f32, vec![4.0, 7.0, 5.0, 9.0, 6.0, 11.0]);
+
+        // lda = 3 leaves the padding row untouched
+        x_f64.as_slice().ger(2, 2, 2.0, 2, &y_f64, 2, &mut a_f64, 3);
+        assert_eq!(a_f64, vec![6.0, 12.0, -1.0, 8.0, 16.0, -1.0]);
+    }
+}
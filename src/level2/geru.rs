@@ -0,0 +1,42 @@
+use num_complex::Complex;
+use crate::level2::ger::ger;
+
+pub trait Geru {
+    type Output;
+    fn geru(&self, m: usize, n: usize, alpha: Self::Output, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize);
+}
+
+impl Geru for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn geru(&self, m: usize, n: usize, alpha: Complex<f32>, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize) {
+        ger(self, m, n, alpha, incx, y, incy, a, lda, false)
+    }
+}
+impl Geru for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn geru(&self, m: usize, n: usize, alpha: Complex<f64>, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize) {
+        ger(self, m, n, alpha, incx, y, incy, a, lda, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_geru() {
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 4];
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0); 4];
+
+        x_complex_f32.as_slice().geru(2, 2, Complex::new(1.0, 0.0), 1, &y_complex_f32, 1, &mut a_complex_f32, 2);
+        assert_eq!(a_complex_f32, vec![Complex::new(0.0, 1.0), Complex::new(-1.0, 0.0), Complex::new(2.0, 0.0), Complex::new(0.0, 2.0)]);
+
+        x_complex_f64.as_slice().geru(2, 2, Complex::new(0.0, 1.0), 1, &y_complex_f64, 1, &mut a_complex_f64, 2);
+        assert_eq!(a_complex_f64, vec![Complex::new(0.0, 0.0), Complex::new(1.0, -1.0), Complex::new(1.0, 2.0), Complex::new(-1.0, 0.0)]);
+    }
+}
@@ -0,0 +1,53 @@
+use num_complex::Complex;
+use crate::level2::sbmv::sbmv;
+use crate::storage::sym_band::SymBandMatrix;
+
+pub trait Hbmv {
+    type Output;
+    fn hbmv(&self, alpha: Self::Output, x: &[Self::Output], incx: usize, beta: Self::Output, y: &mut [Self::Output], incy: usize);
+}
+
+impl Hbmv for SymBandMatrix<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn hbmv(&self, alpha: Complex<f32>, x: &[Complex<f32>], incx: usize, beta: Complex<f32>, y: &mut [Complex<f32>], incy: usize) {
+        sbmv(self, alpha, x, incx, beta, y, incy, true)
+    }
+}
+impl Hbmv for SymBandMatrix<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn hbmv(&self, alpha: Complex<f64>, x: &[Complex<f64>], incx: usize, beta: Complex<f64>, y: &mut [Complex<f64>], incy: usize) {
+        sbmv(self, alpha, x, incx, beta, y, incy, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Uplo;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_hbmv() {
+        // [[2, 1-i, 0], [1+i, 3, 0], [0, 0, 1]] with one off-diagonal; diagonal imaginary parts are ignored.
+        let a_complex_f32: SymBandMatrix<Complex<f32>> = SymBandMatrix::new(Uplo::Upper, 3, 1, vec![
+            Complex::new(0.0, 0.0), Complex::new(2.0, 7.0),
+            Complex::new(1.0, -1.0), Complex::new(3.0, 7.0),
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+        ]);
+        let mut y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0); 3];
+        let a_complex_f64: SymBandMatrix<Complex<f64>> = SymBandMatrix::new(Uplo::Lower, 3, 1, vec![
+            Complex::new(2.0, 0.0), Complex::new(1.0, 1.0),
+            Complex::new(3.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+        ]);
+        let mut y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 3];
+        let x_complex_f32 = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let x_complex_f64 = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+
+        a_complex_f32.hbmv(Complex::new(1.0, 0.0), &x_complex_f32, 1, Complex::new(1.0, 0.0), &mut y_complex_f32, 1);
+        assert_eq!(y_complex_f32, vec![Complex::new(4.0, 1.0), Complex::new(2.0, 4.0), Complex::new(3.0, 0.0)]);
+
+        a_complex_f64.hbmv(Complex::new(0.0, 1.0), &x_complex_f64, 1, Complex::new(0.0, 0.0), &mut y_complex_f64, 1);
+        assert_eq!(y_complex_f64, vec![Complex::new(-1.0, 3.0), Complex::new(-4.0, 1.0), Complex::new(0.0, 2.0)]);
+    }
+}
@@ -0,0 +1,44 @@
+use num_complex::Complex;
+use crate::level2::symv::symv;
+use crate::types::Uplo;
+
+pub trait Hemv {
+    type Output;
+    fn hemv(&self, uplo: Uplo, n: usize, alpha: Self::Output, lda: usize, x: &Self, incx: usize, beta: Self::Output, y: &mut Self, incy: usize);
+}
+
+impl Hemv for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn hemv(&self, uplo: Uplo, n: usize, alpha: Complex<f32>, lda: usize, x: &Self, incx: usize, beta: Complex<f32>, y: &mut Self, incy: usize) {
+        symv(self, uplo, n, alpha, lda, x, incx, beta, y, incy, true)
+    }
+}
+impl Hemv for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn hemv(&self, uplo: Uplo, n: usize, alpha: Complex<f64>, lda: usize, x: &Self, incx: usize, beta: Complex<f64>, y: &mut Self, incy: usize) {
+        symv(self, uplo, n, alpha, lda, x, incx, beta, y, incy, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_hemv() {
+        // Full matrix [[2, 1-i], [1+i, 3]]; the diagonal imaginary parts are ignored.
+        let upper_complex_f32: Vec<Complex<f32>> = vec![Complex::new(2.0, 7.0), Complex::new(-9.0, -9.0), Complex::new(1.0, -1.0), Complex::new(3.0, 7.0)];
+        let mut y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0); 2];
+        let lower_complex_f64: Vec<Complex<f64>> = vec![Complex::new(2.0, 0.0), Complex::new(1.0, 1.0), Complex::new(-9.0, -9.0), Complex::new(3.0, 0.0)];
+        let mut y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 2];
+        let x_complex_f32 = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let x_complex_f64 = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+
+        upper_complex_f32.as_slice().hemv(Uplo::Upper, 2, Complex::new(1.0, 0.0), 2, &x_complex_f32, 1, Complex::new(1.0, 0.0), &mut y_complex_f32, 1);
+        assert_eq!(y_complex_f32, vec![Complex::new(4.0, 1.0), Complex::new(2.0, 4.0)]);
+
+        lower_complex_f64.as_slice().hemv(Uplo::Lower, 2, Complex::new(0.0, 1.0), 2, &x_complex_f64, 1, Complex::new(0.0, 0.0), &mut y_complex_f64, 1);
+        assert_eq!(y_complex_f64, vec![Complex::new(-1.0, 3.0), Complex::new(-4.0, 1.0)]);
+    }
+}
@@ -0,0 +1,42 @@
+use num_complex::Complex;
+use crate::level2::syr::{syr_columns, triangle_columns};
+use crate::types::Uplo;
+
+pub trait Her {
+    type Output;
+    fn her(&self, uplo: Uplo, n: usize, alpha: Self::Output, incx: usize, a: &mut [Complex<Self::Output>], lda: usize);
+}
+
+impl Her for [Complex<f32>] {
+    type Output = f32;
+    fn her(&self, uplo: Uplo, n: usize, alpha: f32, incx: usize, a: &mut [Complex<f32>], lda: usize) {
+        syr_columns(self, Complex::new(alpha, 0.0), incx, triangle_columns(a, uplo, n, lda), uplo, n, true)
+    }
+}
+impl Her for [Complex<f64>] {
+    type Output = f64;
+    fn her(&self, uplo: Uplo, n: usize, alpha: f64, incx: usize, a: &mut [Complex<f64>], lda: usize) {
+        syr_columns(self, Complex::new(alpha, 0.0), incx, triangle_columns(a, uplo, n, lda), uplo, n, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_her() {
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)];
+        let mut a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 4];
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)];
+        // Stray imaginary parts on the diagonal are dropped by the update.
+        let mut a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 5.0), Complex::new(0.0, 0.0), Complex::new(9.0, 9.0), Complex::new(1.0, -5.0)];
+
+        x_complex_f32.as_slice().her(Uplo::Upper, 2, 1.0, 1, &mut a_complex_f32, 2);
+        assert_eq!(a_complex_f32, vec![Complex::new(2.0, 0.0), Complex::new(0.0, 0.0), Complex::new(2.0, -2.0), Complex::new(4.0, 0.0)]);
+
+        x_complex_f64.as_slice().her(Uplo::Lower, 2, 0.5, 1, &mut a_complex_f64, 2);
+        assert_eq!(a_complex_f64, vec![Complex::new(2.0, 0.0), Complex::new(1.0, 1.0), Complex::new(9.0, 9.0), Complex::new(3.0, 0.0)]);
+    }
+}
@@ -0,0 +1,46 @@
+use num_complex::Complex;
+use crate::level2::syr::triangle_columns;
+use crate::level2::syr2::syr2_columns;
+use crate::types::Uplo;
+
+pub trait Her2 {
+    type Output;
+    fn her2(&self, uplo: Uplo, n: usize, alpha: Self::Output, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize);
+}
+
+impl Her2 for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn her2(&self, uplo: Uplo, n: usize, alpha: Complex<f32>, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize) {
+        syr2_columns(self, alpha, incx, y, incy, triangle_columns(a, uplo, n, lda), uplo, n, true)
+    }
+}
+impl Her2 for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn her2(&self, uplo: Uplo, n: usize, alpha: Complex<f64>, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize) {
+        syr2_columns(self, alpha, incx, y, incy, triangle_columns(a, uplo, n, lda), uplo, n, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_her2() {
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 4];
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 4];
+
+        // x y^H + y x^H = [[0, 3], [3, 0]]
+        x_complex_f32.as_slice().her2(Uplo::Upper, 2, Complex::new(1.0, 0.0), 1, &y_complex_f32, 1, &mut a_complex_f32, 2);
+        assert_eq!(a_complex_f32, vec![Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(3.0, 0.0), Complex::new(0.0, 0.0)]);
+
+        // i x y^H - i y x^H = [[2, i], [-i, -4]]
+        x_complex_f64.as_slice().her2(Uplo::Lower, 2, Complex::new(0.0, 1.0), 1, &y_complex_f64, 1, &mut a_complex_f64, 2);
+        assert_eq!(a_complex_f64, vec![Complex::new(2.0, 0.0), Complex::new(0.0, -1.0), Complex::new(0.0, 0.0), Complex::new(-4.0, 0.0)]);
+    }
+}
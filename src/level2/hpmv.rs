@@ -0,0 +1,43 @@
+use num_complex::Complex;
+use crate::level2::spmv::spmv;
+use crate::storage::packed::PackedMatrix;
+
+pub trait Hpmv {
+    type Output;
+    fn hpmv(&self, alpha: Self::Output, x: &[Self::Output], incx: usize, beta: Self::Output, y: &mut [Self::Output], incy: usize);
+}
+
+impl Hpmv for PackedMatrix<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn hpmv(&self, alpha: Complex<f32>, x: &[Complex<f32>], incx: usize, beta: Complex<f32>, y: &mut [Complex<f32>], incy: usize) {
+        spmv(self, alpha, x, incx, beta, y, incy, true)
+    }
+}
+impl Hpmv for PackedMatrix<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn hpmv(&self, alpha: Complex<f64>, x: &[Complex<f64>], incx: usize, beta: Complex<f64>, y: &mut [Complex<f64>], incy: usize) {
+        spmv(self, alpha, x, incx, beta, y, incy, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Uplo;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_hpmv() {
+        // [[2, 1-i], [1+i, 3]]; the diagonal imaginary parts are ignored.
+        let ap_complex_f32: PackedMatrix<Complex<f32>> = PackedMatrix::new(Uplo::Upper, 2, vec![Complex::new(2.0, 7.0), Complex::new(1.0, -1.0), Complex::new(3.0, 7.0)]);
+        let mut y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0); 2];
+        let ap_complex_f64: PackedMatrix<Complex<f64>> = PackedMatrix::new(Uplo::Lower, 2, vec![Complex::new(2.0, 0.0), Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)]);
+        let mut y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 2];
+
+        ap_complex_f32.hpmv(Complex::new(1.0, 0.0), &[Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)], 1, Complex::new(1.0, 0.0), &mut y_complex_f32, 1);
+        assert_eq!(y_complex_f32, vec![Complex::new(4.0, 1.0), Complex::new(2.0, 4.0)]);
+
+        ap_complex_f64.hpmv(Complex::new(0.0, 1.0), &[Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)], 1, Complex::new(0.0, 0.0), &mut y_complex_f64, 1);
+        assert_eq!(y_complex_f64, vec![Complex::new(-1.0, 3.0), Complex::new(-4.0, 1.0)]);
+    }
+}
@@ -0,0 +1,43 @@
+use num_complex::Complex;
+use crate::level2::spr::spr;
+use crate::storage::packed::PackedMatrix;
+
+pub trait Hpr {
+    type Output;
+    fn hpr(&self, alpha: Self::Output, incx: usize, ap: &mut PackedMatrix<Complex<Self::Output>>);
+}
+
+impl Hpr for [Complex<f32>] {
+    type Output = f32;
+    fn hpr(&self, alpha: f32, incx: usize, ap: &mut PackedMatrix<Complex<f32>>) {
+        spr(self, Complex::new(alpha, 0.0), incx, ap, true)
+    }
+}
+impl Hpr for [Complex<f64>] {
+    type Output = f64;
+    fn hpr(&self, alpha: f64, incx: usize, ap: &mut PackedMatrix<Complex<f64>>) {
+        spr(self, Complex::new(alpha, 0.0), incx, ap, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Uplo;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_hpr() {
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)];
+        let mut ap_complex_f32: PackedMatrix<Complex<f32>> = PackedMatrix::zeros(Uplo::Upper, 2);
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)];
+        // Stray imaginary parts on the diagonal are dropped by the update.
+        let mut ap_complex_f64: PackedMatrix<Complex<f64>> = PackedMatrix::new(Uplo::Lower, 2, vec![Complex::new(1.0, 5.0), Complex::new(0.0, 0.0), Complex::new(1.0, -5.0)]);
+
+        x_complex_f32.as_slice().hpr(1.0, 1, &mut ap_complex_f32);
+        assert_eq!(ap_complex_f32.as_slice(), &[Complex::new(2.0, 0.0), Complex::new(2.0, -2.0), Complex::new(4.0, 0.0)]);
+
+        x_complex_f64.as_slice().hpr(0.5, 1, &mut ap_complex_f64);
+        assert_eq!(ap_complex_f64.as_slice(), &[Complex::new(2.0, 0.0), Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)]);
+    }
+}
@@ -0,0 +1,46 @@
+use num_complex::Complex;
+use crate::level2::spr2::spr2;
+use crate::storage::packed::PackedMatrix;
+
+pub trait Hpr2 {
+    type Output;
+    fn hpr2(&self, alpha: Self::Output, incx: usize, y: &Self, incy: usize, ap: &mut PackedMatrix<Self::Output>);
+}
+
+impl Hpr2 for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn hpr2(&self, alpha: Complex<f32>, incx: usize, y: &Self, incy: usize, ap: &mut PackedMatrix<Complex<f32>>) {
+        spr2(self, alpha, incx, y, incy, ap, true)
+    }
+}
+impl Hpr2 for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn hpr2(&self, alpha: Complex<f64>, incx: usize, y: &Self, incy: usize, ap: &mut PackedMatrix<Complex<f64>>) {
+        spr2(self, alpha, incx, y, incy, ap, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Uplo;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_hpr2() {
+        let x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let y_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut ap_complex_f32: PackedMatrix<Complex<f32>> = PackedMatrix::zeros(Uplo::Upper, 2);
+        let x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let y_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut ap_complex_f64: PackedMatrix<Complex<f64>> = PackedMatrix::zeros(Uplo::Lower, 2);
+
+        // x y^H + y x^H = [[0, 3], [3, 0]]
+        x_complex_f32.as_slice().hpr2(Complex::new(1.0, 0.0), 1, &y_complex_f32, 1, &mut ap_complex_f32);
+        assert_eq!(ap_complex_f32.as_slice(), &[Complex::new(0.0, 0.0), Complex::new(3.0, 0.0), Complex::new(0.0, 0.0)]);
+
+        // i x y^H - i y x^H = [[2, i], [-i, -4]]
+        x_complex_f64.as_slice().hpr2(Complex::new(0.0, 1.0), 1, &y_complex_f64, 1, &mut ap_complex_f64);
+        assert_eq!(ap_complex_f64.as_slice(), &[Complex::new(2.0, 0.0), Complex::new(0.0, -1.0), Complex::new(-4.0, 0.0)]);
+    }
+}
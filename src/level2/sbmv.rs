@@ -0,0 +1,76 @@
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::storage::sym_band::SymBandMatrix;
+
+pub trait Sbmv {
+    type Output;
+    fn sbmv(&self, alpha: Self::Output, x: &[Self::Output], incx: usize, beta: Self::Output, y: &mut [Self::Output], incy: usize);
+}
+
+impl Sbmv for SymBandMatrix<f32> {
+    type Output = f32;
+    fn sbmv(&self, alpha: f32, x: &[f32], incx: usize, beta: f32, y: &mut [f32], incy: usize) {
+        sbmv(self, alpha, x, incx, beta, y, incy, false)
+    }
+}
+impl Sbmv for SymBandMatrix<f64> {
+    type Output = f64;
+    fn sbmv(&self, alpha: f64, x: &[f64], incx: usize, beta: f64, y: &mut [f64], incy: usize) {
+        sbmv(self, alpha, x, incx, beta, y, incy, false)
+    }
+}
+
+// Element (i, j) of the full symmetric (or Hermitian, when `conj`) band matrix.
+// The Hermitian diagonal is taken as real.
+fn sym_band_at<T: Scalar>(a: &SymBandMatrix<T>, conj: bool, i: usize, j: usize) -> T {
+    if !conj {
+        a.get(i, j)
+    } else if i == j {
+        T::from_real(a.get(i, i).re())
+    } else if a.col_range(j).contains(&i) {
+        a.get(i, j)
+    } else {
+        a.get(i, j).conj()
+    }
+}
+
+// y = alpha * A * x + beta * y, one dot product over the band per element of y.
+pub(crate) fn sbmv<T: Scalar>(a: &SymBandMatrix<T>, alpha: T, x: &[T], incx: usize, beta: T, y: &mut [T], incy: usize, conj: bool) {
+    let n = a.n();
+    let k = a.k();
+    y
+    .par_iter_mut()
+    .step_by(incy)
+    .take(n)
+    .enumerate()
+    .for_each(|(i, y)| {
+        let mut sum = T::zero();
+        if alpha != T::zero() {
+            for j in i.saturating_sub(k)..(i + k + 1).min(n) {
+                sum += sym_band_at(a, conj, i, j) * x[j * incx];
+            }
+        }
+        *y = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * *y };
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Uplo;
+
+    #[test]
+    fn test_sbmv() {
+        // [[1, 2, 0], [2, 3, 4], [0, 4, 5]]
+        let a_f32: SymBandMatrix<f32> = SymBandMatrix::new(Uplo::Upper, 3, 1, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        let mut y_f32: Vec<f32> = vec![1.0; 3];
+        let a_f64: SymBandMatrix<f64> = SymBandMatrix::new(Uplo::Lower, 3, 1, vec![1.0, 2.0, 3.0, 4.0, 5.0, 0.0]);
+        let mut y_f64: Vec<f64> = vec![f64::NAN, 0.0, f64::NAN, 0.0, f64::NAN, 0.0];
+
+        a_f32.sbmv(1.0, &[1.0, 1.0, 1.0], 1, 1.0, &mut y_f32, 1);
+        assert_eq!(y_f32, vec![4.0, 10.0, 10.0]);
+
+        a_f64.sbmv(2.0, &[1.0, 0.0, 0.0, 0.0, -1.0], 2, 0.0, &mut y_f64, 2);
+        assert_eq!(y_f64, vec![2.0, 0.0, -4.0, 0.0, -10.0, 0.0]);
+    }
+}
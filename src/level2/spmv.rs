@@ -0,0 +1,75 @@
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::storage::packed::PackedMatrix;
+
+pub trait Spmv {
+    type Output;
+    fn spmv(&self, alpha: Self::Output, x: &[Self::Output], incx: usize, beta: Self::Output, y: &mut [Self::Output], incy: usize);
+}
+
+impl Spmv for PackedMatrix<f32> {
+    type Output = f32;
+    fn spmv(&self, alpha: f32, x: &[f32], incx: usize, beta: f32, y: &mut [f32], incy: usize) {
+        spmv(self, alpha, x, incx, beta, y, incy, false)
+    }
+}
+impl Spmv for PackedMatrix<f64> {
+    type Output = f64;
+    fn spmv(&self, alpha: f64, x: &[f64], incx: usize, beta: f64, y: &mut [f64], incy: usize) {
+        spmv(self, alpha, x, incx, beta, y, incy, false)
+    }
+}
+
+// Element (i, j) of the full symmetric (or Hermitian, when `conj`) matrix held in `ap`.
+// The Hermitian diagonal is taken as real.
+pub(crate) fn packed_at<T: Scalar>(ap: &PackedMatrix<T>, conj: bool, i: usize, j: usize) -> T {
+    if !conj {
+        ap.get(i, j)
+    } else if i == j {
+        T::from_real(ap.get(i, i).re())
+    } else if ap.col_range(j).contains(&i) {
+        ap.get(i, j)
+    } else {
+        ap.get(i, j).conj()
+    }
+}
+
+// y = alpha * A * x + beta * y, one independent dot product per element of y.
+pub(crate) fn spmv<T: Scalar>(ap: &PackedMatrix<T>, alpha: T, x: &[T], incx: usize, beta: T, y: &mut [T], incy: usize, conj: bool) {
+    let n = ap.n();
+    y
+    .par_iter_mut()
+    .step_by(incy)
+    .take(n)
+    .enumerate()
+    .for_each(|(i, y)| {
+        let mut sum = T::zero();
+        if alpha != T::zero() {
+            for j in 0..n {
+                sum += packed_at(ap, conj, i, j) * x[j * incx];
+            }
+        }
+        *y = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * *y };
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Uplo;
+
+    #[test]
+    fn test_spmv() {
+        // [[1, 2, 3], [2, 4, 5], [3, 5, 6]]
+        let ap_f32: PackedMatrix<f32> = PackedMatrix::new(Uplo::Upper, 3, vec![1.0, 2.0, 4.0, 3.0, 5.0, 6.0]);
+        let mut y_f32: Vec<f32> = vec![1.0; 3];
+        let ap_f64: PackedMatrix<f64> = PackedMatrix::new(Uplo::Lower, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let mut y_f64: Vec<f64> = vec![f64::NAN, 0.0, f64::NAN, 0.0, f64::NAN, 0.0];
+
+        ap_f32.spmv(1.0, &[1.0, 1.0, 1.0], 1, 1.0, &mut y_f32, 1);
+        assert_eq!(y_f32, vec![7.0, 12.0, 15.0]);
+
+        ap_f64.spmv(2.0, &[1.0, 0.0, 0.0, 0.0, -1.0], 2, 0.0, &mut y_f64, 2);
+        assert_eq!(y_f64, vec![-4.0, 0.0, -6.0, 0.0, -6.0, 0.0]);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::level2::syr::syr_columns;
+use crate::scalar::Scalar;
+use crate::storage::packed::PackedMatrix;
+
+pub trait Spr {
+    type Output;
+    fn spr(&self, alpha: Self::Output, incx: usize, ap: &mut PackedMatrix<Self::Output>);
+}
+
+impl Spr for [f32] {
+    type Output = f32;
+    fn spr(&self, alpha: f32, incx: usize, ap: &mut PackedMatrix<f32>) {
+        spr(self, alpha, incx, ap, false)
+    }
+}
+impl Spr for [f64] {
+    type Output = f64;
+    fn spr(&self, alpha: f64, incx: usize, ap: &mut PackedMatrix<f64>) {
+        spr(self, alpha, incx, ap, false)
+    }
+}
+
+// The packed rank-1 update runs the dense column kernel over the packed columns.
+pub(crate) fn spr<T: Scalar>(x: &[T], alpha: T, incx: usize, ap: &mut PackedMatrix<T>, conj: bool) {
+    let uplo = ap.uplo();
+    let n = ap.n();
+    syr_columns(x, alpha, incx, ap.columns_mut(), uplo, n, conj)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Uplo;
+
+    #[test]
+    fn test_spr() {
+        let x_f32: Vec<f32> = vec![1.0, 2.0, 3.0];
+        let mut ap_f32: PackedMatrix<f32> = PackedMatrix::zeros(Uplo::Upper, 3);
+        let x_f64: Vec<f64> = vec![1.0, 0.0, -1.0, 0.0];
+        let mut ap_f64: PackedMatrix<f64> = PackedMatrix::new(Uplo::Lower, 2, vec![1.0, 1.0, 1.0]);
+
+        x_f32.as_slice().spr(1.0, 1, &mut ap_f32);
+        assert_eq!(ap_f32.as_slice(), &[1.0, 2.0, 4.0, 3.0, 6.0, 9.0]);
+
+        x_f64.as_slice().spr(2.0, 2, &mut ap_f64);
+        assert_eq!(ap_f64.as_slice(), &[3.0, -1.0, 3.0]);
+    }
+}
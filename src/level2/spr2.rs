@@ -0,0 +1,50 @@
+use crate::level2::syr2::syr2_columns;
+use crate::scalar::Scalar;
+use crate::storage::packed::PackedMatrix;
+
+pub trait Spr2 {
+    type Output;
+    fn spr2(&self, alpha: Self::Output, incx: usize, y: &Self, incy: usize, ap: &mut PackedMatrix<Self::Output>);
+}
+
+impl Spr2 for [f32] {
+    type Output = f32;
+    fn spr2(&self, alpha: f32, incx: usize, y: &Self, incy: usize, ap: &mut PackedMatrix<f32>) {
+        spr2(self, alpha, incx, y, incy, ap, false)
+    }
+}
+impl Spr2 for [f64] {
+    type Output = f64;
+    fn spr2(&self, alpha: f64, incx: usize, y: &Self, incy: usize, ap: &mut PackedMatrix<f64>) {
+        spr2(self, alpha, incx, y, incy, ap, false)
+    }
+}
+
+// The packed rank-2 update runs the dense column kernel over the packed columns.
+pub(crate) fn spr2<T: Scalar>(x: &[T], alpha: T, incx: usize, y: &[T], incy: usize, ap: &mut PackedMatrix<T>, conj: bool) {
+    let uplo = ap.uplo();
+    let n = ap.n();
+    syr2_columns(x, alpha, incx, y, incy, ap.columns_mut(), uplo, n, conj)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Uplo;
+
+    #[test]
+    fn test_spr2() {
+        let x_f32: Vec<f32> = vec![1.0, 2.0];
+        let y_f32: Vec<f32> = vec![3.0, 4.0];
+        let mut ap_f32: PackedMatrix<f32> = PackedMatrix::zeros(Uplo::Upper, 2);
+        let x_f64: Vec<f64> = vec![1.0, 0.0, 2.0];
+        let y_f64: Vec<f64> = vec![3.0, 4.0];
+        let mut ap_f64: PackedMatrix<f64> = PackedMatrix::new(Uplo::Lower, 2, vec![1.0, 1.0, 1.0]);
+
+        x_f32.as_slice().spr2(1.0, 1, &y_f32, 1, &mut ap_f32);
+        assert_eq!(ap_f32.as_slice(), &[6.0, 10.0, 16.0]);
+
+        x_f64.as_slice().spr2(0.5, 2, &y_f64, 1, &mut ap_f64);
+        assert_eq!(ap_f64.as_slice(), &[4.0, 6.0, 9.0]);
+    }
+}
@@ -0,0 +1,78 @@
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::types::Uplo;
+
+pub trait Symv {
+    type Output;
+    fn symv(&self, uplo: Uplo, n: usize, alpha: Self::Output, lda: usize, x: &Self, incx: usize, beta: Self::Output, y: &mut Self, incy: usize);
+}
+
+impl Symv for [f32] {
+    type Output = f32;
+    fn symv(&self, uplo: Uplo, n: usize, alpha: f32, lda: usize, x: &Self, incx: usize, beta: f32, y: &mut Self, incy: usize) {
+        symv(self, uplo, n, alpha, lda, x, incx, beta, y, incy, false)
+    }
+}
+impl Symv for [f64] {
+    type Output = f64;
+    fn symv(&self, uplo: Uplo, n: usize, alpha: f64, lda: usize, x: &Self, incx: usize, beta: f64, y: &mut Self, incy: usize) {
+        symv(self, uplo, n, alpha, lda, x, incx, beta, y, incy, false)
+    }
+}
+
+// Element (i, j) of the full symmetric (or Hermitian, when `conj`) matrix whose `uplo`
+// triangle is stored in `a`. The Hermitian diagonal is taken as real.
+pub(crate) fn sym_at<T: Scalar>(a: &[T], lda: usize, uplo: Uplo, conj: bool, i: usize, j: usize) -> T {
+    let stored = match uplo {
+        Uplo::Upper => i <= j,
+        Uplo::Lower => i >= j,
+    };
+    if i == j && conj {
+        T::from_real(a[i + i * lda].re())
+    } else if stored {
+        a[i + j * lda]
+    } else if conj {
+        a[j + i * lda].conj()
+    } else {
+        a[j + i * lda]
+    }
+}
+
+// y = alpha * A * x + beta * y with A symmetric (or Hermitian, when `conj`). Each element
+// of y is computed independently, so rows are processed in parallel.
+pub(crate) fn symv<T: Scalar>(a: &[T], uplo: Uplo, n: usize, alpha: T, lda: usize, x: &[T], incx: usize, beta: T, y: &mut [T], incy: usize, conj: bool) {
+    y
+    .par_iter_mut()
+    .step_by(incy)
+    .take(n)
+    .enumerate()
+    .for_each(|(i, y)| {
+        let mut sum = T::zero();
+        if alpha != T::zero() {
+            for j in 0..n {
+                sum += sym_at(a, lda, uplo, conj, i, j) * x[j * incx];
+            }
+        }
+        *y = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * *y };
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symv() {
+        // Full matrix [[1, 2, 3], [2, 4, 5], [3, 5, 6]]; the unused triangle holds garbage.
+        let a_f32: Vec<f32> = vec![1.0, 2.0, 3.0, -9.0, 4.0, 5.0, -9.0, -9.0, 6.0];
+        let mut y_f32: Vec<f32> = vec![1.0; 3];
+        let a_f64: Vec<f64> = vec![1.0, -9.0, -9.0, 0.0, 2.0, 4.0, -9.0, 0.0, 3.0, 5.0, 6.0, 0.0];
+        let mut y_f64: Vec<f64> = vec![f64::NAN, 0.0, f64::NAN, 0.0, f64::NAN, 0.0];
+
+        a_f32.as_slice().symv(Uplo::Lower, 3, 1.0, 3, &[1.0, 1.0, 1.0], 1, 1.0, &mut y_f32, 1);
+        assert_eq!(y_f32, vec![7.0, 12.0, 15.0]);
+
+        a_f64.as_slice().symv(Uplo::Upper, 3, 2.0, 4, &[1.0, 0.0, -1.0], 1, 0.0, &mut y_f64, 2);
+        assert_eq!(y_f64, vec![-4.0, 0.0, -6.0, 0.0, -6.0, 0.0]);
+    }
+}
@@ -0,0 +1,80 @@
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::types::Uplo;
+
+pub trait Syr {
+    type Output;
+    fn syr(&self, uplo: Uplo, n: usize, alpha: Self::Output, incx: usize, a: &mut Self, lda: usize);
+}
+
+impl Syr for [f32] {
+    type Output = f32;
+    fn syr(&self, uplo: Uplo, n: usize, alpha: f32, incx: usize, a: &mut Self, lda: usize) {
+        syr_columns(self, alpha, incx, triangle_columns(a, uplo, n, lda), uplo, n, false)
+    }
+}
+impl Syr for [f64] {
+    type Output = f64;
+    fn syr(&self, uplo: Uplo, n: usize, alpha: f64, incx: usize, a: &mut Self, lda: usize) {
+        syr_columns(self, alpha, incx, triangle_columns(a, uplo, n, lda), uplo, n, false)
+    }
+}
+
+// Splits a dense n x n matrix into the `uplo` part of each column: rows 0..=j for Upper,
+// j..n for Lower.
+pub(crate) fn triangle_columns<T>(a: &mut [T], uplo: Uplo, n: usize, lda: usize) -> Vec<(usize, &mut [T])> {
+    a
+    .chunks_mut(lda)
+    .take(n)
+    .enumerate()
+    .map(|(j, col)| match uplo {
+        Uplo::Upper => (j, &mut col[..j + 1]),
+        Uplo::Lower => (j, &mut col[j..n]),
+    })
+    .collect()
+}
+
+// A = alpha * x * x^T (or x * x^H, when `conj`) + A over triangle columns as produced by
+// `triangle_columns` or `PackedMatrix::columns_mut`, in parallel. The Hermitian diagonal is
+// written back as exactly real.
+pub(crate) fn syr_columns<T: Scalar>(x: &[T], alpha: T, incx: usize, cols: Vec<(usize, &mut [T])>, uplo: Uplo, n: usize, conj: bool) {
+    if alpha == T::zero() {
+        return;
+    }
+    cols
+    .into_par_iter()
+    .for_each(|(j, col)| {
+        let xj = x[j * incx];
+        let temp = alpha * if conj { xj.conj() } else { xj };
+        let rows = match uplo {
+            Uplo::Upper => 0..j + 1,
+            Uplo::Lower => j..n,
+        };
+        for (a, i) in col.iter_mut().zip(rows) {
+            *a += x[i * incx] * temp;
+            if conj && i == j {
+                *a = T::from_real(a.re());
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syr() {
+        let x_f32: Vec<f32> = vec![1.0, 2.0, 3.0];
+        let mut a_f32: Vec<f32> = vec![0.0; 9];
+        let x_f64: Vec<f64> = vec![1.0, 0.0, -1.0, 0.0];
+        let mut a_f64: Vec<f64> = vec![1.0, 1.0, 1.0, 1.0];
+
+        // The strictly lower triangle is left alone
+        x_f32.as_slice().syr(Uplo::Upper, 3, 1.0, 1, &mut a_f32, 3);
+        assert_eq!(a_f32, vec![1.0, 0.0, 0.0, 2.0, 4.0, 0.0, 3.0, 6.0, 9.0]);
+
+        x_f64.as_slice().syr(Uplo::Lower, 2, 2.0, 2, &mut a_f64, 2);
+        assert_eq!(a_f64, vec![3.0, -1.0, 1.0, 3.0]);
+    }
+}
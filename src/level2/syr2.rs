@@ -0,0 +1,68 @@
+use rayon::prelude::*;
+use crate::level2::syr::triangle_columns;
+use crate::scalar::Scalar;
+use crate::types::Uplo;
+
+pub trait Syr2 {
+    type Output;
+    fn syr2(&self, uplo: Uplo, n: usize, alpha: Self::Output, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize);
+}
+
+impl Syr2 for [f32] {
+    type Output = f32;
+    fn syr2(&self, uplo: Uplo, n: usize, alpha: f32, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize) {
+        syr2_columns(self, alpha, incx, y, incy, triangle_columns(a, uplo, n, lda), uplo, n, false)
+    }
+}
+impl Syr2 for [f64] {
+    type Output = f64;
+    fn syr2(&self, uplo: Uplo, n: usize, alpha: f64, incx: usize, y: &Self, incy: usize, a: &mut Self, lda: usize) {
+        syr2_columns(self, alpha, incx, y, incy, triangle_columns(a, uplo, n, lda), uplo, n, false)
+    }
+}
+
+// A = alpha * x * y^T + alpha * y * x^T + A, or with `conj`
+// A = alpha * x * y^H + conj(alpha) * y * x^H + A, over triangle columns, in parallel.
+pub(crate) fn syr2_columns<T: Scalar>(x: &[T], alpha: T, incx: usize, y: &[T], incy: usize, cols: Vec<(usize, &mut [T])>, uplo: Uplo, n: usize, conj: bool) {
+    if alpha == T::zero() {
+        return;
+    }
+    let c = |v: T| if conj { v.conj() } else { v };
+    cols
+    .into_par_iter()
+    .for_each(|(j, col)| {
+        let temp1 = alpha * c(y[j * incy]);
+        let temp2 = c(alpha * x[j * incx]);
+        let rows = match uplo {
+            Uplo::Upper => 0..j + 1,
+            Uplo::Lower => j..n,
+        };
+        for (a, i) in col.iter_mut().zip(rows) {
+            *a += x[i * incx] * temp1 + y[i * incy] * temp2;
+            if conj && i == j {
+                *a = T::from_real(a.re());
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syr2() {
+        let x_f32: Vec<f32> = vec![1.0, 2.0];
+        let y_f32: Vec<f32> = vec![3.0, 4.0];
+        let mut a_f32: Vec<f32> = vec![0.0; 4];
+        let x_f64: Vec<f64> = vec![1.0, 0.0, 2.0];
+        let y_f64: Vec<f64> = vec![3.0, 4.0];
+        let mut a_f64: Vec<f64> = vec![1.0, 1.0, 1.0, 1.0];
+
+        x_f32.as_slice().syr2(Uplo::Upper, 2, 1.0, 1, &y_f32, 1, &mut a_f32, 2);
+        assert_eq!(a_f32, vec![6.0, 0.0, 10.0, 16.0]);
+
+        x_f64.as_slice().syr2(Uplo::Lower, 2, 0.5, 2, &y_f64, 1, &mut a_f64, 2);
+        assert_eq!(a_f64, vec![4.0, 6.0, 1.0, 9.0]);
+    }
+}
@@ -0,0 +1,92 @@
+use num_complex::Complex;
+use crate::level2::trmv::{op_is_lower, tri_mv};
+use crate::scalar::Scalar;
+use crate::storage::band::BandMatrix;
+use crate::types::{Diag, Trans, Uplo};
+
+pub trait Tbmv {
+    type Output;
+    fn tbmv(&self, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [Self::Output], incx: usize);
+}
+
+impl Tbmv for BandMatrix<f32> {
+    type Output = f32;
+    fn tbmv(&self, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [f32], incx: usize) {
+        tbmv(self, uplo, trans, diag, x, incx)
+    }
+}
+impl Tbmv for BandMatrix<f64> {
+    type Output = f64;
+    fn tbmv(&self, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [f64], incx: usize) {
+        tbmv(self, uplo, trans, diag, x, incx)
+    }
+}
+impl Tbmv for BandMatrix<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn tbmv(&self, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [Complex<f32>], incx: usize) {
+        tbmv(self, uplo, trans, diag, x, incx)
+    }
+}
+impl Tbmv for BandMatrix<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn tbmv(&self, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [Complex<f64>], incx: usize) {
+        tbmv(self, uplo, trans, diag, x, incx)
+    }
+}
+
+// Number of off-diagonals in the `uplo` triangle of a square band matrix.
+pub(crate) fn band_tri_k<T: Scalar>(a: &BandMatrix<T>, uplo: Uplo) -> usize {
+    assert_eq!(a.rows(), a.cols(), "triangular band matrix must be square");
+    match uplo {
+        Uplo::Upper => a.ku(),
+        Uplo::Lower => a.kl(),
+    }
+}
+
+// Element (i, j) of op(A) for a band matrix A.
+pub(crate) fn band_op_at<T: Scalar>(a: &BandMatrix<T>, trans: Trans, i: usize, j: usize) -> T {
+    match trans {
+        Trans::NoTrans => a.get(i, j),
+        Trans::Trans => a.get(j, i),
+        Trans::ConjTrans => a.get(j, i).conj(),
+    }
+}
+
+// x = op(A) * x using only the `uplo` triangle of the band.
+fn tbmv<T: Scalar>(a: &BandMatrix<T>, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [T], incx: usize) {
+    let k = band_tri_k(a, uplo);
+    tri_mv(a.cols(), k, op_is_lower(uplo, trans), diag == Diag::Unit, |i, j| band_op_at(a, trans, i, j), x, incx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_tbmv() {
+        // Upper bidiagonal [[1, 2, 0], [0, 3, 4], [0, 0, 5]] (kl = 0, ku = 1)
+        let a_f32: BandMatrix<f32> = BandMatrix::new(3, 3, 0, 1, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        let mut x_f32: Vec<f32> = vec![1.0, 1.0, 1.0];
+        // Tridiagonal band; only the lower triangle [[1, 0, 0], [6, 3, 0], [0, 7, 5]] is used
+        let a_f64: BandMatrix<f64> = BandMatrix::new(3, 3, 1, 1, vec![0.0, 1.0, 6.0, 2.0, 3.0, 7.0, 4.0, 5.0, 0.0]);
+        let mut x_f64: Vec<f64> = vec![1.0, 0.0, 1.0, 0.0, 1.0];
+        // Lower bidiagonal [[1+i, 0], [2, 3i]] (kl = 1, ku = 0)
+        let a_complex_f32: BandMatrix<Complex<f32>> = BandMatrix::new(2, 2, 1, 0, vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0), Complex::new(0.0, 0.0)]);
+        let mut x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let a_complex_f64: BandMatrix<Complex<f64>> = BandMatrix::new(2, 2, 1, 0, vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0), Complex::new(0.0, 0.0)]);
+        let mut x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+
+        a_f32.tbmv(Uplo::Upper, Trans::NoTrans, Diag::NonUnit, &mut x_f32, 1);
+        assert_eq!(x_f32, vec![3.0, 7.0, 5.0]);
+
+        a_f64.tbmv(Uplo::Lower, Trans::Trans, Diag::Unit, &mut x_f64, 2);
+        assert_eq!(x_f64, vec![7.0, 0.0, 8.0, 0.0, 1.0]);
+
+        a_complex_f32.tbmv(Uplo::Lower, Trans::NoTrans, Diag::NonUnit, &mut x_complex_f32, 1);
+        assert_eq!(x_complex_f32, vec![Complex::new(1.0, 1.0), Complex::new(-1.0, 0.0)]);
+
+        a_complex_f64.tbmv(Uplo::Lower, Trans::ConjTrans, Diag::NonUnit, &mut x_complex_f64, 1);
+        assert_eq!(x_complex_f64, vec![Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)]);
+    }
+}
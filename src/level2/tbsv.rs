@@ -0,0 +1,88 @@
+use num_complex::Complex;
+use crate::level2::tbmv::{band_op_at, band_tri_k};
+use crate::level2::trmv::op_is_lower;
+use crate::level2::trsv::tri_sv;
+use crate::scalar::Scalar;
+use crate::storage::band::BandMatrix;
+use crate::types::{Diag, Trans, Uplo};
+
+pub trait Tbsv {
+    type Output;
+    fn tbsv(&self, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [Self::Output], incx: usize);
+}
+
+impl Tbsv for BandMatrix<f32> {
+    type Output = f32;
+    fn tbsv(&self, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [f32], incx: usize) {
+        tbsv(self, uplo, trans, diag, x, incx)
+    }
+}
+impl Tbsv for BandMatrix<f64> {
+    type Output = f64;
+    fn tbsv(&self, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [f64], incx: usize) {
+        tbsv(self, uplo, trans, diag, x, incx)
+    }
+}
+impl Tbsv for BandMatrix<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn tbsv(&self, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [Complex<f32>], incx: usize) {
+        tbsv(self, uplo, trans, diag, x, incx)
+    }
+}
+impl Tbsv for BandMatrix<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn tbsv(&self, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [Complex<f64>], incx: usize) {
+        tbsv(self, uplo, trans, diag, x, incx)
+    }
+}
+
+// Solves op(A) * x = b in place using only the `uplo` triangle of the band.
+fn tbsv<T: Scalar>(a: &BandMatrix<T>, uplo: Uplo, trans: Trans, diag: Diag, x: &mut [T], incx: usize) {
+    let k = band_tri_k(a, uplo);
+    tri_sv(a.cols(), k, op_is_lower(uplo, trans), diag == Diag::Unit, |i, j| band_op_at(a, trans, i, j), x, incx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level2::tbmv::Tbmv;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_tbsv() {
+        let a_f32: BandMatrix<f32> = BandMatrix::new(3, 3, 0, 1, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        let mut x_f32: Vec<f32> = vec![3.0, 7.0, 5.0];
+        let a_f64: BandMatrix<f64> = BandMatrix::new(3, 3, 1, 1, vec![0.0, 1.0, 6.0, 2.0, 3.0, 7.0, 4.0, 5.0, 0.0]);
+        let mut x_f64: Vec<f64> = vec![7.0, 0.0, 8.0, 0.0, 1.0];
+        let a_complex_f32: BandMatrix<Complex<f32>> = BandMatrix::new(2, 2, 1, 0, vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0), Complex::new(0.0, 0.0)]);
+        let mut x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(-1.0, 0.0)];
+        let a_complex_f64: BandMatrix<Complex<f64>> = BandMatrix::new(2, 2, 1, 0, vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0), Complex::new(0.0, 0.0)]);
+        let mut x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)];
+
+        a_f32.tbsv(Uplo::Upper, Trans::NoTrans, Diag::NonUnit, &mut x_f32, 1);
+        assert_eq!(x_f32, vec![1.0, 1.0, 1.0]);
+
+        a_f64.tbsv(Uplo::Lower, Trans::Trans, Diag::Unit, &mut x_f64, 2);
+        assert_eq!(x_f64, vec![1.0, 0.0, 1.0, 0.0, 1.0]);
+
+        a_complex_f32.tbsv(Uplo::Lower, Trans::NoTrans, Diag::NonUnit, &mut x_complex_f32, 1);
+        assert_eq!(x_complex_f32, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+
+        a_complex_f64.tbsv(Uplo::Lower, Trans::ConjTrans, Diag::NonUnit, &mut x_complex_f64, 1);
+        assert_eq!(x_complex_f64, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+
+        // Banded Cholesky-like factor spanning several solve blocks
+        let n = 200;
+        let dense: Vec<f64> = (0..n * n).map(|k| if k % (n + 1) == 0 { 4.0 } else { ((k * 7) % 11) as f64 / 11.0 }).collect();
+        let a = BandMatrix::from_dense(n, n, 3, 2, &dense, n);
+        let x: Vec<f64> = (0..n).map(|i| (i % 5) as f64 - 2.0).collect();
+        for uplo in [Uplo::Upper, Uplo::Lower] {
+            for trans in [Trans::NoTrans, Trans::Trans] {
+                let mut b = x.clone();
+                a.tbmv(uplo, trans, Diag::NonUnit, &mut b, 1);
+                a.tbsv(uplo, trans, Diag::NonUnit, &mut b, 1);
+                assert!(b.iter().zip(&x).all(|(b, x)| (b - x).abs() < 1e-10));
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use num_complex::Complex;
+use crate::level2::trmv::{op_is_lower, tri_mv};
+use crate::scalar::Scalar;
+use crate::storage::packed::PackedMatrix;
+use crate::types::{Diag, Trans};
+
+pub trait Tpmv {
+    type Output;
+    fn tpmv(&self, trans: Trans, diag: Diag, x: &mut [Self::Output], incx: usize);
+}
+
+impl Tpmv for PackedMatrix<f32> {
+    type Output = f32;
+    fn tpmv(&self, trans: Trans, diag: Diag, x: &mut [f32], incx: usize) {
+        tpmv(self, trans, diag, x, incx)
+    }
+}
+impl Tpmv for PackedMatrix<f64> {
+    type Output = f64;
+    fn tpmv(&self, trans: Trans, diag: Diag, x: &mut [f64], incx: usize) {
+        tpmv(self, trans, diag, x, incx)
+    }
+}
+impl Tpmv for PackedMatrix<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn tpmv(&self, trans: Trans, diag: Diag, x: &mut [Complex<f32>], incx: usize) {
+        tpmv(self, trans, diag, x, incx)
+    }
+}
+impl Tpmv for PackedMatrix<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn tpmv(&self, trans: Trans, diag: Diag, x: &mut [Complex<f64>], incx: usize) {
+        tpmv(self, trans, diag, x, incx)
+    }
+}
+
+// Element (i, j) of op(A) for a packed triangular A; (i, j) must lie in op(A)'s triangle.
+pub(crate) fn packed_op_at<T: Scalar>(ap: &PackedMatrix<T>, trans: Trans, i: usize, j: usize) -> T {
+    let data = ap.as_slice();
+    match trans {
+        Trans::NoTrans => data[ap.index(i, j)],
+        Trans::Trans => data[ap.index(j, i)],
+        Trans::ConjTrans => data[ap.index(j, i)].conj(),
+    }
+}
+
+// x = op(A) * x with the triangle given by the packed storage.
+fn tpmv<T: Scalar>(ap: &PackedMatrix<T>, trans: Trans, diag: Diag, x: &mut [T], incx: usize) {
+    let n = ap.n();
+    tri_mv(n, n, op_is_lower(ap.uplo(), trans), diag == Diag::Unit, |i, j| packed_op_at(ap, trans, i, j), x, incx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Uplo;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_tpmv() {
+        // [[1, 2, 3], [0, 4, 5], [0, 0, 6]]
+        let ap_f32: PackedMatrix<f32> = PackedMatrix::new(Uplo::Upper, 3, vec![1.0, 2.0, 4.0, 3.0, 5.0, 6.0]);
+        let mut x_f32: Vec<f32> = vec![1.0, 1.0, 1.0];
+        let ap_f64: PackedMatrix<f64> = PackedMatrix::new(Uplo::Upper, 3, vec![1.0, 2.0, 4.0, 3.0, 5.0, 6.0]);
+        let mut x_f64: Vec<f64> = vec![1.0, 0.0, 1.0, 0.0, 1.0];
+        // [[1+i, 0], [2, 3i]]
+        let ap_complex_f32: PackedMatrix<Complex<f32>> = PackedMatrix::new(Uplo::Lower, 2, vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0)]);
+        let mut x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let ap_complex_f64: PackedMatrix<Complex<f64>> = PackedMatrix::new(Uplo::Lower, 2, vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0)]);
+        let mut x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+
+        ap_f32.tpmv(Trans::NoTrans, Diag::NonUnit, &mut x_f32, 1);
+        assert_eq!(x_f32, vec![6.0, 9.0, 6.0]);
+
+        ap_f64.tpmv(Trans::Trans, Diag::Unit, &mut x_f64, 2);
+        assert_eq!(x_f64, vec![1.0, 0.0, 3.0, 0.0, 9.0]);
+
+        ap_complex_f32.tpmv(Trans::NoTrans, Diag::NonUnit, &mut x_complex_f32, 1);
+        assert_eq!(x_complex_f32, vec![Complex::new(1.0, 1.0), Complex::new(-1.0, 0.0)]);
+
+        ap_complex_f64.tpmv(Trans::ConjTrans, Diag::NonUnit, &mut x_complex_f64, 1);
+        assert_eq!(x_complex_f64, vec![Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)]);
+    }
+}
@@ -0,0 +1,74 @@
+use num_complex::Complex;
+use crate::level2::tpmv::packed_op_at;
+use crate::level2::trmv::op_is_lower;
+use crate::level2::trsv::tri_sv;
+use crate::scalar::Scalar;
+use crate::storage::packed::PackedMatrix;
+use crate::types::{Diag, Trans};
+
+pub trait Tpsv {
+    type Output;
+    fn tpsv(&self, trans: Trans, diag: Diag, x: &mut [Self::Output], incx: usize);
+}
+
+impl Tpsv for PackedMatrix<f32> {
+    type Output = f32;
+    fn tpsv(&self, trans: Trans, diag: Diag, x: &mut [f32], incx: usize) {
+        tpsv(self, trans, diag, x, incx)
+    }
+}
+impl Tpsv for PackedMatrix<f64> {
+    type Output = f64;
+    fn tpsv(&self, trans: Trans, diag: Diag, x: &mut [f64], incx: usize) {
+        tpsv(self, trans, diag, x, incx)
+    }
+}
+impl Tpsv for PackedMatrix<Complex<f32>> {
+    type Output = Complex<f32>;
+    fn tpsv(&self, trans: Trans, diag: Diag, x: &mut [Complex<f32>], incx: usize) {
+        tpsv(self, trans, diag, x, incx)
+    }
+}
+impl Tpsv for PackedMatrix<Complex<f64>> {
+    type Output = Complex<f64>;
+    fn tpsv(&self, trans: Trans, diag: Diag, x: &mut [Complex<f64>], incx: usize) {
+        tpsv(self, trans, diag, x, incx)
+    }
+}
+
+// Solves op(A) * x = b in place with the triangle given by the packed storage.
+fn tpsv<T: Scalar>(ap: &PackedMatrix<T>, trans: Trans, diag: Diag, x: &mut [T], incx: usize) {
+    let n = ap.n();
+    tri_sv(n, n, op_is_lower(ap.uplo(), trans), diag == Diag::Unit, |i, j| packed_op_at(ap, trans, i, j), x, incx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Uplo;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_tpsv() {
+        let ap_f32: PackedMatrix<f32> = PackedMatrix::new(Uplo::Upper, 3, vec![1.0, 2.0, 4.0, 3.0, 5.0, 6.0]);
+        let mut x_f32: Vec<f32> = vec![6.0, 9.0, 6.0];
+        let ap_f64: PackedMatrix<f64> = PackedMatrix::new(Uplo::Upper, 3, vec![1.0, 2.0, 4.0, 3.0, 5.0, 6.0]);
+        let mut x_f64: Vec<f64> = vec![1.0, 0.0, 3.0, 0.0, 9.0];
+        let ap_complex_f32: PackedMatrix<Complex<f32>> = PackedMatrix::new(Uplo::Lower, 2, vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0)]);
+        let mut x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(-1.0, 0.0)];
+        let ap_complex_f64: PackedMatrix<Complex<f64>> = PackedMatrix::new(Uplo::Lower, 2, vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 3.0)]);
+        let mut x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)];
+
+        ap_f32.tpsv(Trans::NoTrans, Diag::NonUnit, &mut x_f32, 1);
+        assert_eq!(x_f32, vec![1.0, 1.0, 1.0]);
+
+        ap_f64.tpsv(Trans::Trans, Diag::Unit, &mut x_f64, 2);
+        assert_eq!(x_f64, vec![1.0, 0.0, 1.0, 0.0, 1.0]);
+
+        ap_complex_f32.tpsv(Trans::NoTrans, Diag::NonUnit, &mut x_complex_f32, 1);
+        assert_eq!(x_complex_f32, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+
+        ap_complex_f64.tpsv(Trans::ConjTrans, Diag::NonUnit, &mut x_complex_f64, 1);
+        assert_eq!(x_complex_f64, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+    }
+}
@@ -0,0 +1,95 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::types::{op_at, Diag, Trans, Uplo};
+
+pub trait Trmv {
+    type Output;
+    fn trmv(&self, uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut Self, incx: usize);
+}
+
+impl Trmv for [f32] {
+    type Output = f32;
+    fn trmv(&self, uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut Self, incx: usize) {
+        trmv(self, uplo, trans, diag, n, lda, x, incx)
+    }
+}
+impl Trmv for [f64] {
+    type Output = f64;
+    fn trmv(&self, uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut Self, incx: usize) {
+        trmv(self, uplo, trans, diag, n, lda, x, incx)
+    }
+}
+impl Trmv for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn trmv(&self, uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut Self, incx: usize) {
+        trmv(self, uplo, trans, diag, n, lda, x, incx)
+    }
+}
+impl Trmv for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn trmv(&self, uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut Self, incx: usize) {
+        trmv(self, uplo, trans, diag, n, lda, x, incx)
+    }
+}
+
+// Whether op(A) is lower triangular for a matrix whose `uplo` triangle is stored.
+pub(crate) fn op_is_lower(uplo: Uplo, trans: Trans) -> bool {
+    (uplo == Uplo::Lower) == (trans == Trans::NoTrans)
+}
+
+fn trmv<T: Scalar>(a: &[T], uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut [T], incx: usize) {
+    tri_mv(n, n, op_is_lower(uplo, trans), diag == Diag::Unit, |i, j| op_at(a, trans, lda, i, j), x, incx)
+}
+
+// x = op(A) * x for a triangular op(A) of order n with k off-diagonals (k >= n - 1 for a
+// full triangle), whose element (i, j) is `at(i, j)`. Every output element reads the
+// original x, so x is copied once and the rows of op(A) are processed in parallel.
+pub(crate) fn tri_mv<T: Scalar, F: Fn(usize, usize) -> T + Sync>(n: usize, k: usize, lower: bool, unit: bool, at: F, x: &mut [T], incx: usize) {
+    let old: Vec<T> = x.iter().step_by(incx).take(n).copied().collect();
+    x
+    .par_iter_mut()
+    .step_by(incx)
+    .take(n)
+    .enumerate()
+    .for_each(|(i, x)| {
+        let mut sum = if unit { old[i] } else { at(i, i) * old[i] };
+        let cols = if lower { i.saturating_sub(k)..i } else { i + 1..(i + k + 1).min(n) };
+        for j in cols {
+            sum += at(i, j) * old[j];
+        }
+        *x = sum;
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_trmv() {
+        // Upper triangle of [[1, 2, 3], [., 4, 5], [., ., 6]], garbage below the diagonal
+        let a_f32: Vec<f32> = vec![1.0, -9.0, -9.0, 2.0, 4.0, -9.0, 3.0, 5.0, 6.0];
+        let mut x_f32: Vec<f32> = vec![1.0, 1.0, 1.0];
+        let a_f64: Vec<f64> = vec![1.0, -9.0, -9.0, 2.0, 4.0, -9.0, 3.0, 5.0, 6.0];
+        let mut x_f64: Vec<f64> = vec![1.0, 0.0, 1.0, 0.0, 1.0];
+        // Lower triangle of [[1+i, .], [2, 3i]]
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(-9.0, 0.0), Complex::new(0.0, 3.0)];
+        let mut x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(-9.0, 0.0), Complex::new(0.0, 3.0)];
+        let mut x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+
+        a_f32.as_slice().trmv(Uplo::Upper, Trans::NoTrans, Diag::NonUnit, 3, 3, &mut x_f32, 1);
+        assert_eq!(x_f32, vec![6.0, 9.0, 6.0]);
+
+        a_f64.as_slice().trmv(Uplo::Upper, Trans::Trans, Diag::Unit, 3, 3, &mut x_f64, 2);
+        assert_eq!(x_f64, vec![1.0, 0.0, 3.0, 0.0, 9.0]);
+
+        a_complex_f32.as_slice().trmv(Uplo::Lower, Trans::NoTrans, Diag::NonUnit, 2, 2, &mut x_complex_f32, 1);
+        assert_eq!(x_complex_f32, vec![Complex::new(1.0, 1.0), Complex::new(-1.0, 0.0)]);
+
+        a_complex_f64.as_slice().trmv(Uplo::Lower, Trans::ConjTrans, Diag::NonUnit, 2, 2, &mut x_complex_f64, 1);
+        assert_eq!(x_complex_f64, vec![Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)]);
+    }
+}
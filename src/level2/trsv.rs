@@ -0,0 +1,152 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::level2::trmv::op_is_lower;
+use crate::scalar::Scalar;
+use crate::types::{op_at, Diag, Trans, Uplo};
+
+// Order of the diagonal blocks solved sequentially between parallel updates.
+const NB: usize = 64;
+
+pub trait Trsv {
+    type Output;
+    fn trsv(&self, uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut Self, incx: usize);
+}
+
+impl Trsv for [f32] {
+    type Output = f32;
+    fn trsv(&self, uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut Self, incx: usize) {
+        trsv(self, uplo, trans, diag, n, lda, x, incx)
+    }
+}
+impl Trsv for [f64] {
+    type Output = f64;
+    fn trsv(&self, uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut Self, incx: usize) {
+        trsv(self, uplo, trans, diag, n, lda, x, incx)
+    }
+}
+impl Trsv for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn trsv(&self, uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut Self, incx: usize) {
+        trsv(self, uplo, trans, diag, n, lda, x, incx)
+    }
+}
+impl Trsv for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn trsv(&self, uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut Self, incx: usize) {
+        trsv(self, uplo, trans, diag, n, lda, x, incx)
+    }
+}
+
+fn trsv<T: Scalar>(a: &[T], uplo: Uplo, trans: Trans, diag: Diag, n: usize, lda: usize, x: &mut [T], incx: usize) {
+    tri_sv(n, n, op_is_lower(uplo, trans), diag == Diag::Unit, |i, j| op_at(a, trans, lda, i, j), x, incx)
+}
+
+// Solves op(A) * x = b in place for a triangular op(A) of order n with k off-diagonals
+// (k >= n - 1 for a full triangle), whose element (i, j) is `at(i, j)`. Diagonal blocks of
+// order NB are solved by substitution; each solved block is then eliminated from the rows
+// it reaches with one dot product per row, in parallel.
+pub(crate) fn tri_sv<T: Scalar, F: Fn(usize, usize) -> T + Sync>(n: usize, k: usize, lower: bool, unit: bool, at: F, x: &mut [T], incx: usize) {
+    let mut b: Vec<T> = x.iter().step_by(incx).take(n).copied().collect();
+    let nblocks = n.div_ceil(NB);
+    for kb in 0..nblocks {
+        let kb = if lower { kb } else { nblocks - 1 - kb };
+        let k0 = kb * NB;
+        let k1 = (k0 + NB).min(n);
+        if lower {
+            for i in k0..k1 {
+                let mut s = b[i];
+                for (j, &xj) in b.iter().enumerate().take(i).skip(k0.max(i.saturating_sub(k))) {
+                    s -= at(i, j) * xj;
+                }
+                b[i] = if unit { s } else { s / at(i, i) };
+            }
+            let (head, tail) = b.split_at_mut(k1);
+            let solved = &head[k0..];
+            let reach = (n - k1).min(k);
+            tail[..reach]
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(r, b)| {
+                let i = k1 + r;
+                let mut s = T::zero();
+                for (j, &xj) in (k0..k1).zip(solved).skip(i.saturating_sub(k).saturating_sub(k0)) {
+                    s += at(i, j) * xj;
+                }
+                *b -= s;
+            });
+        } else {
+            for i in (k0..k1).rev() {
+                let mut s = b[i];
+                for (j, &xj) in b.iter().enumerate().take(k1.min(i + k + 1)).skip(i + 1) {
+                    s -= at(i, j) * xj;
+                }
+                b[i] = if unit { s } else { s / at(i, i) };
+            }
+            let (head, tail) = b.split_at_mut(k0);
+            let solved = &tail[..k1 - k0];
+            let first = k0.saturating_sub(k);
+            head[first..]
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(r, b)| {
+                let i = first + r;
+                let mut s = T::zero();
+                for (j, &xj) in (k0..k1.min(i + k + 1)).zip(solved) {
+                    s += at(i, j) * xj;
+                }
+                *b -= s;
+            });
+        }
+    }
+    x
+    .iter_mut()
+    .step_by(incx)
+    .zip(b)
+    .for_each(|(x, b)| *x = b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level2::trmv::Trmv;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_trsv() {
+        // Upper triangle of [[1, 2, 3], [., 4, 5], [., ., 6]], garbage below the diagonal
+        let a_f32: Vec<f32> = vec![1.0, -9.0, -9.0, 2.0, 4.0, -9.0, 3.0, 5.0, 6.0];
+        let mut x_f32: Vec<f32> = vec![6.0, 9.0, 6.0];
+        let a_f64: Vec<f64> = vec![1.0, -9.0, -9.0, 2.0, 4.0, -9.0, 3.0, 5.0, 6.0];
+        let mut x_f64: Vec<f64> = vec![1.0, 0.0, 3.0, 0.0, 9.0];
+        // Lower triangle of [[1+i, .], [2, 3i]]
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(-9.0, 0.0), Complex::new(0.0, 3.0)];
+        let mut x_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(-1.0, 0.0)];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(-9.0, 0.0), Complex::new(0.0, 3.0)];
+        let mut x_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)];
+
+        a_f32.as_slice().trsv(Uplo::Upper, Trans::NoTrans, Diag::NonUnit, 3, 3, &mut x_f32, 1);
+        assert_eq!(x_f32, vec![1.0, 1.0, 1.0]);
+
+        a_f64.as_slice().trsv(Uplo::Upper, Trans::Trans, Diag::Unit, 3, 3, &mut x_f64, 2);
+        assert_eq!(x_f64, vec![1.0, 0.0, 1.0, 0.0, 1.0]);
+
+        a_complex_f32.as_slice().trsv(Uplo::Lower, Trans::NoTrans, Diag::NonUnit, 2, 2, &mut x_complex_f32, 1);
+        assert_eq!(x_complex_f32, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+
+        a_complex_f64.as_slice().trsv(Uplo::Lower, Trans::ConjTrans, Diag::NonUnit, 2, 2, &mut x_complex_f64, 1);
+        assert_eq!(x_complex_f64, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+
+        // Several blocks: solving after multiplying recovers x for every option combination
+        let n = 150;
+        let a: Vec<f64> = (0..n * n).map(|k| if k % (n + 1) == 0 { n as f64 } else { ((k * 7) % 11) as f64 / 11.0 }).collect();
+        let x: Vec<f64> = (0..n).map(|i| (i % 5) as f64 - 2.0).collect();
+        for uplo in [Uplo::Upper, Uplo::Lower] {
+            for trans in [Trans::NoTrans, Trans::Trans] {
+                let mut b = x.clone();
+                a.as_slice().trmv(uplo, trans, Diag::NonUnit, n, n, &mut b, 1);
+                a.as_slice().trsv(uplo, trans, Diag::NonUnit, n, n, &mut b, 1);
+                assert!(b.iter().zip(&x).all(|(b, x)| (b - x).abs() < 1e-10));
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+// Matrix-matrix operations
+pub mod gemm;
+pub mod gemmt;
+pub mod gemm_batch;
+pub mod gemm_strided_batch;
+pub mod gemm3m;
+pub mod gemm_strassen;
+pub mod gemm_ex;
+pub mod gemm_i8;
+pub mod gemm_semiring;
+pub mod gemm_modular;
+pub mod symm;
+pub mod hemm;
+pub mod syrk;
+pub mod herk;
+pub mod syr2k;
+pub mod her2k;
+pub mod trmm;
+pub mod trsm;
@@ -0,0 +1,381 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::scalar::Scalar;
+use crate::types::{op_at, Trans, Uplo};
+
+// Register tile computed by the micro-kernel.
+pub(crate) const MR: usize = 4;
+pub(crate) const NR: usize = 4;
+// Cache blocks: an MC x KC block of A and a KC x NR sliver of B are reused from cache.
+const MC: usize = 128;
+const KC: usize = 256;
+// Widest column panel of C handed to one task.
+const NC: usize = 512;
+
+// Element arithmetic of the blocked driver. Register tiles accumulate in `Acc`, which is the
+// element type itself for every `Scalar`; other element types can widen it so that each tile
+// is reduced once per KC block rather than once per multiply-add.
+pub(crate) trait Accumulator: Copy + Send + Sync {
+    type Acc: Copy;
+    // Value padding partial slivers when packing.
+    fn padding() -> Self;
+    fn is_zero(self) -> bool;
+    fn is_one(self) -> bool;
+    fn acc_zero() -> Self::Acc;
+    // acc += a * b
+    fn mul_add(acc: &mut Self::Acc, a: Self, b: Self);
+    // beta * self; a zero beta overwrites self without reading it
+    fn scaled(self, beta: Self) -> Self;
+    // self += alpha * acc
+    fn add_scaled(&mut self, alpha: Self, acc: Self::Acc);
+}
+
+impl<T: Scalar> Accumulator for T {
+    type Acc = T;
+    fn padding() -> T {
+        T::zero()
+    }
+    fn is_zero(self) -> bool {
+        self == T::zero()
+    }
+    fn is_one(self) -> bool {
+        self == T::one()
+    }
+    fn acc_zero() -> T {
+        T::zero()
+    }
+    fn mul_add(acc: &mut T, a: T, b: T) {
+        *acc += a * b;
+    }
+    fn scaled(self, beta: T) -> T {
+        if beta == T::zero() { T::zero() } else { beta * self }
+    }
+    fn add_scaled(&mut self, alpha: T, acc: T) {
+        *self += alpha * acc;
+    }
+}
+
+pub trait Gemm {
+    type Output;
+    fn gemm(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Self::Output, lda: usize, b: &Self, ldb: usize, beta: Self::Output, c: &mut Self, ldc: usize);
+}
+
+impl Gemm for [f32] {
+    type Output = f32;
+    fn gemm(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: f32, lda: usize, b: &Self, ldb: usize, beta: f32, c: &mut Self, ldc: usize) {
+        gemm(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+impl Gemm for [f64] {
+    type Output = f64;
+    fn gemm(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: f64, lda: usize, b: &Self, ldb: usize, beta: f64, c: &mut Self, ldc: usize) {
+        gemm(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+impl Gemm for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn gemm(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Complex<f32>, lda: usize, b: &Self, ldb: usize, beta: Complex<f32>, c: &mut Self, ldc: usize) {
+        gemm(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+impl Gemm for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn gemm(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Complex<f64>, lda: usize, b: &Self, ldb: usize, beta: Complex<f64>, c: &mut Self, ldc: usize) {
+        gemm(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+
+fn gemm<T: Scalar>(a: &[T], transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: T, lda: usize, b: &[T], ldb: usize, beta: T, c: &mut [T], ldc: usize) {
+    gemm_blocked(m, n, k, alpha, |i, p| op_at(a, transa, lda, i, p), |p, j| op_at(b, transb, ldb, p, j), beta, c, ldc)
+}
+
+// C = alpha * A * B + beta * C for an m x n C, where element (i, p) of the m x k A is
+// `a_at(i, p)` and element (p, j) of the k x n B is `b_at(p, j)`. The accessors are only
+// called while packing, so transposition, symmetry or type conversion cost nothing in the
+// inner loops. C is split into column panels that are computed in parallel; within a panel,
+// B is packed KC rows at a time and A in MC x KC blocks, and an MR x NR micro-kernel
+// accumulates each register tile.
+pub(crate) fn gemm_blocked<T, FA, FB>(m: usize, n: usize, k: usize, alpha: T, a_at: FA, b_at: FB, beta: T, c: &mut [T], ldc: usize)
+where
+    T: Accumulator,
+    FA: Fn(usize, usize) -> T + Sync,
+    FB: Fn(usize, usize) -> T + Sync,
+{
+    gemm_driver(m, n, k, alpha, a_at, b_at, beta, c, ldc, None)
+}
+
+// As `gemm_blocked` for an n x n C, but only the `uplo` triangle of C is computed or
+// touched. Row blocks wholly outside the triangle are never packed, register tiles wholly
+// outside it are skipped, and tiles straddling the diagonal are masked on write-back.
+pub(crate) fn gemm_blocked_triangle<T, FA, FB>(uplo: Uplo, n: usize, k: usize, alpha: T, a_at: FA, b_at: FB, beta: T, c: &mut [T], ldc: usize)
+where
+    T: Accumulator,
+    FA: Fn(usize, usize) -> T + Sync,
+    FB: Fn(usize, usize) -> T + Sync,
+{
+    gemm_driver(n, n, k, alpha, a_at, b_at, beta, c, ldc, Some(uplo))
+}
+
+fn gemm_driver<T, FA, FB>(m: usize, n: usize, k: usize, alpha: T, a_at: FA, b_at: FB, beta: T, c: &mut [T], ldc: usize, tri: Option<Uplo>)
+where
+    T: Accumulator,
+    FA: Fn(usize, usize) -> T + Sync,
+    FB: Fn(usize, usize) -> T + Sync,
+{
+    if m == 0 || n == 0 {
+        return;
+    }
+    let nb = panel_width(n);
+    c
+    .par_chunks_mut(ldc * nb)
+    .take(n.div_ceil(nb))
+    .enumerate()
+    .for_each(|(jb, c_panel)| {
+        let jc = jb * nb;
+        gemm_panel(m, k, alpha, &a_at, &b_at, beta, c_panel, ldc, jc, nb.min(n - jc), tri)
+    })
+}
+
+// As `gemm_blocked`, but on the calling thread only, for callers that already parallelize
+// over many small independent products.
+pub(crate) fn gemm_serial<T, FA, FB>(m: usize, n: usize, k: usize, alpha: T, a_at: FA, b_at: FB, beta: T, c: &mut [T], ldc: usize)
+where
+    T: Accumulator,
+    FA: Fn(usize, usize) -> T,
+    FB: Fn(usize, usize) -> T,
+{
+    if m == 0 || n == 0 {
+        return;
+    }
+    c
+    .chunks_mut(ldc * NC)
+    .take(n.div_ceil(NC))
+    .enumerate()
+    .for_each(|(jb, c_panel)| {
+        let jc = jb * NC;
+        gemm_panel(m, k, alpha, &a_at, &b_at, beta, c_panel, ldc, jc, NC.min(n - jc), None)
+    })
+}
+
+// Columns jc..jc + ncols of C, held in `c_panel`: B is packed KC rows at a time and A in
+// MC x KC blocks, restricted to the rows that reach the `tri` triangle if one is given.
+fn gemm_panel<T, FA, FB>(m: usize, k: usize, alpha: T, a_at: &FA, b_at: &FB, beta: T, c_panel: &mut [T], ldc: usize, jc: usize, ncols: usize, tri: Option<Uplo>)
+where
+    T: Accumulator,
+    FA: Fn(usize, usize) -> T,
+    FB: Fn(usize, usize) -> T,
+{
+    let rows = match tri {
+        None => 0..m,
+        Some(Uplo::Upper) => 0..(jc + ncols).min(m),
+        Some(Uplo::Lower) => jc..m,
+    };
+    match tri {
+        None => scale(c_panel, m, ncols, ldc, beta),
+        Some(uplo) => scale_triangle(c_panel, uplo, jc, m, ncols, ldc, beta),
+    }
+    if alpha.is_zero() || k == 0 {
+        return;
+    }
+    let mut a_pack = Vec::with_capacity(MC.min(m).next_multiple_of(MR) * KC.min(k));
+    let mut b_pack = Vec::with_capacity(KC.min(k) * ncols.next_multiple_of(NR));
+    for pc in (0..k).step_by(KC) {
+        let kc = KC.min(k - pc);
+        pack_b(b_at, pc, kc, jc, ncols, &mut b_pack);
+        for ic in rows.clone().step_by(MC) {
+            let mc = MC.min(rows.end - ic);
+            pack_a(a_at, ic, mc, pc, kc, &mut a_pack);
+            match tri {
+                None => macro_kernel(mc, ncols, kc, alpha, &a_pack, &b_pack, c_panel, ic, ldc),
+                Some(uplo) => macro_kernel_triangle(uplo, jc, mc, ncols, kc, alpha, &a_pack, &b_pack, c_panel, ic, ldc),
+            }
+        }
+    }
+}
+
+// Columns of C per task: enough panels to occupy every thread, in whole micro-tiles.
+pub(crate) fn panel_width(n: usize) -> usize {
+    n
+    .div_ceil(rayon::current_num_threads())
+    .next_multiple_of(NR)
+    .clamp(NR, NC)
+}
+
+// C = beta * C over an m x ncols panel. A zero beta overwrites C without reading it.
+pub(crate) fn scale<T: Accumulator>(c: &mut [T], m: usize, ncols: usize, ldc: usize, beta: T) {
+    if beta.is_one() {
+        return;
+    }
+    c
+    .chunks_mut(ldc)
+    .take(ncols)
+    .for_each(|col| {
+        col[..m]
+        .iter_mut()
+        .for_each(|c| *c = c.scaled(beta));
+    })
+}
+
+// C = beta * C over the `uplo` triangle of a panel whose first column is column jc of C.
+fn scale_triangle<T: Accumulator>(c: &mut [T], uplo: Uplo, jc: usize, m: usize, ncols: usize, ldc: usize, beta: T) {
+    if beta.is_one() {
+        return;
+    }
+    c
+    .chunks_mut(ldc)
+    .take(ncols)
+    .enumerate()
+    .for_each(|(j, col)| {
+        let rows = match uplo {
+            Uplo::Upper => 0..(jc + j + 1).min(m),
+            Uplo::Lower => (jc + j).min(m)..m,
+        };
+        col[rows]
+        .iter_mut()
+        .for_each(|c| *c = c.scaled(beta));
+    })
+}
+
+// Packs A(ic..ic + mc, pc..pc + kc) as MR-row slivers, each stored column by column and
+// padded with `T::padding()` to a full MR rows.
+pub(crate) fn pack_a<T: Accumulator, F: Fn(usize, usize) -> T>(a_at: &F, ic: usize, mc: usize, pc: usize, kc: usize, a_pack: &mut Vec<T>) {
+    a_pack.clear();
+    for ir in (0..mc).step_by(MR) {
+        for p in pc..pc + kc {
+            for i in ic + ir..ic + ir + MR {
+                a_pack.push(if i < ic + mc { a_at(i, p) } else { T::padding() });
+            }
+        }
+    }
+}
+
+// Packs B(pc..pc + kc, jc..jc + ncols) as NR-column slivers, each stored row by row and
+// padded with `T::padding()` to a full NR columns.
+pub(crate) fn pack_b<T: Accumulator, F: Fn(usize, usize) -> T>(b_at: &F, pc: usize, kc: usize, jc: usize, ncols: usize, b_pack: &mut Vec<T>) {
+    b_pack.clear();
+    for jr in (0..ncols).step_by(NR) {
+        for p in pc..pc + kc {
+            for j in jc + jr..jc + jr + NR {
+                b_pack.push(if j < jc + ncols { b_at(p, j) } else { T::padding() });
+            }
+        }
+    }
+}
+
+// C(ic.., 0..ncols) += alpha * packed A * packed B, one register tile at a time.
+pub(crate) fn macro_kernel<T: Accumulator>(mc: usize, ncols: usize, kc: usize, alpha: T, a_pack: &[T], b_pack: &[T], c: &mut [T], ic: usize, ldc: usize) {
+    for (jr, b_sliver) in b_pack.chunks(NR * kc).enumerate() {
+        let j0 = jr * NR;
+        let nr = NR.min(ncols - j0);
+        for (ir, a_sliver) in a_pack.chunks(MR * kc).enumerate() {
+            let i0 = ic + ir * MR;
+            let mr = MR.min(mc - ir * MR);
+            let acc = micro_kernel(a_sliver, b_sliver);
+            for (j, acc_col) in acc.iter().enumerate().take(nr) {
+                let c_col = &mut c[i0 + (j0 + j) * ldc..];
+                for (c, &acc) in c_col.iter_mut().zip(acc_col).take(mr) {
+                    c.add_scaled(alpha, acc);
+                }
+            }
+        }
+    }
+}
+
+// `macro_kernel` restricted to the `uplo` triangle of C, for a panel starting at column jc.
+fn macro_kernel_triangle<T: Accumulator>(uplo: Uplo, jc: usize, mc: usize, ncols: usize, kc: usize, alpha: T, a_pack: &[T], b_pack: &[T], c: &mut [T], ic: usize, ldc: usize) {
+    let in_triangle = |i: usize, j: usize| match uplo {
+        Uplo::Upper => i <= j,
+        Uplo::Lower => i >= j,
+    };
+    for (jr, b_sliver) in b_pack.chunks(NR * kc).enumerate() {
+        let j0 = jr * NR;
+        let nr = NR.min(ncols - j0);
+        for (ir, a_sliver) in a_pack.chunks(MR * kc).enumerate() {
+            let i0 = ic + ir * MR;
+            let mr = MR.min(mc - ir * MR);
+            // Skip tiles with no element in the triangle: test the corner nearest to it.
+            let touches = match uplo {
+                Uplo::Upper => in_triangle(i0, jc + j0 + nr - 1),
+                Uplo::Lower => in_triangle(i0 + mr - 1, jc + j0),
+            };
+            if !touches {
+                continue;
+            }
+            let acc = micro_kernel(a_sliver, b_sliver);
+            for (j, acc_col) in acc.iter().enumerate().take(nr) {
+                let c_col = &mut c[i0 + (j0 + j) * ldc..];
+                for (i, (c, &acc)) in c_col.iter_mut().zip(acc_col).take(mr).enumerate() {
+                    if in_triangle(i0 + i, jc + j0 + j) {
+                        c.add_scaled(alpha, acc);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// MR x NR block of the product of an MR-row sliver of A and an NR-column sliver of B.
+pub(crate) fn micro_kernel<T: Accumulator>(a_sliver: &[T], b_sliver: &[T]) -> [[T::Acc; MR]; NR] {
+    let mut acc = [[T::acc_zero(); MR]; NR];
+    for (a, b) in a_sliver.chunks_exact(MR).zip(b_sliver.chunks_exact(NR)) {
+        for (acc_col, &b) in acc.iter_mut().zip(b) {
+            for (acc, &a) in acc_col.iter_mut().zip(a) {
+                T::mul_add(acc, a, b);
+            }
+        }
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_gemm() {
+        // A = [[1, 2, 3], [4, 5, 6]], B = [[1, 0], [0, 1], [1, 1]]
+        let a_f32: Vec<f32> = vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+        let b_f32: Vec<f32> = vec![1.0, 0.0, 1.0, 0.0, 1.0, 1.0];
+        let mut c_f32: Vec<f32> = vec![1.0; 4];
+        // A^T stored 3 x 2 with lda = 4, B^T stored 2 x 3
+        let a_f64: Vec<f64> = vec![1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0];
+        let b_f64: Vec<f64> = vec![1.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let mut c_f64: Vec<f64> = vec![f64::NAN; 4];
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 1.0), Complex::new(1.0, -1.0)];
+        let b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 2];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 1.0), Complex::new(1.0, -1.0)];
+        let b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0); 2];
+
+        a_f32.as_slice().gemm(Trans::NoTrans, Trans::NoTrans, 2, 2, 3, 1.0, 2, &b_f32, 3, 2.0, &mut c_f32, 2);
+        assert_eq!(c_f32, vec![6.0, 12.0, 7.0, 13.0]);
+
+        a_f64.as_slice().gemm(Trans::Trans, Trans::Trans, 2, 2, 3, 2.0, 4, &b_f64, 2, 0.0, &mut c_f64, 2);
+        assert_eq!(c_f64, vec![8.0, 20.0, 10.0, 22.0]);
+
+        // [[1+i, i], [2, 1-i]] * [1, i]
+        a_complex_f32.as_slice().gemm(Trans::NoTrans, Trans::NoTrans, 2, 1, 2, Complex::new(1.0, 0.0), 2, &b_complex_f32, 2, Complex::new(0.0, 0.0), &mut c_complex_f32, 2);
+        assert_eq!(c_complex_f32, vec![Complex::new(0.0, 1.0), Complex::new(3.0, 1.0)]);
+
+        // [[1-i, 2], [-i, 1+i]] * [1, i] + [1, 1]
+        a_complex_f64.as_slice().gemm(Trans::ConjTrans, Trans::NoTrans, 2, 1, 2, Complex::new(1.0, 0.0), 2, &b_complex_f64, 2, Complex::new(1.0, 0.0), &mut c_complex_f64, 2);
+        assert_eq!(c_complex_f64, vec![Complex::new(2.0, 1.0), Complex::new(0.0, 0.0)]);
+
+        // Sizes that cross every blocking boundary, against a reference triple loop
+        let (m, n, k) = (261, 70, 300);
+        let a: Vec<f64> = (0..m * k).map(|v| ((v * 7) % 13) as f64 - 6.0).collect();
+        let b: Vec<f64> = (0..k * n).map(|v| ((v * 5) % 11) as f64 - 5.0).collect();
+        let mut c: Vec<f64> = (0..m * n).map(|v| (v % 3) as f64).collect();
+        let mut expected = c.clone();
+        for j in 0..n {
+            for i in 0..m {
+                let dot: f64 = (0..k).map(|p| a[p + i * k] * b[p + j * k]).sum();
+                expected[i + j * m] = 2.0 * dot - expected[i + j * m];
+            }
+        }
+        a.as_slice().gemm(Trans::Trans, Trans::NoTrans, m, n, k, 2.0, k, &b, k, -1.0, &mut c, m);
+        assert_eq!(c, expected);
+    }
+}
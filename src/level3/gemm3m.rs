@@ -0,0 +1,127 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::level3::gemm::gemm_blocked;
+use crate::scalar::Scalar;
+use crate::types::{op_at, Trans};
+
+// Complex GEMM with three real products instead of four (the 3M method):
+//
+//   T1 = Re(A) * Re(B),  T2 = Im(A) * Im(B),  T3 = (Re(A) + Im(A)) * (Re(B) + Im(B))
+//   A * B = (T1 - T2) + i * (T3 - T1 - T2)
+//
+// This saves about a quarter of the multiplications of `Gemm::gemm` on complex data, at two
+// costs. The three m x n real products are held in a workspace of 3 * m * n reals. And the
+// imaginary part is recovered by cancellation, so its error is bounded by
+// (|Re(A)| + |Im(A)|) * (|Re(B)| + |Im(B)|) rather than by |Re(A)| * |Im(B)| + |Im(A)| * |Re(B)|:
+// the result is as accurate as the standard product in norm, but an imaginary part that is
+// small next to the magnitudes involved can lose most of its relative accuracy. Use `gemm`
+// when individual small components matter.
+pub trait Gemm3m {
+    type Output;
+    fn gemm3m(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Self::Output, lda: usize, b: &Self, ldb: usize, beta: Self::Output, c: &mut Self, ldc: usize);
+}
+
+impl Gemm3m for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn gemm3m(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Complex<f32>, lda: usize, b: &Self, ldb: usize, beta: Complex<f32>, c: &mut Self, ldc: usize) {
+        gemm3m(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+impl Gemm3m for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn gemm3m(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Complex<f64>, lda: usize, b: &Self, ldb: usize, beta: Complex<f64>, c: &mut Self, ldc: usize) {
+        gemm3m(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+
+// C = alpha * op(A) * op(B) + beta * C for complex A, B and C. The real and imaginary parts
+// are split out by the packing accessors, so the three products run on the real GEMM driver.
+fn gemm3m<R>(a: &[Complex<R>], transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Complex<R>, lda: usize, b: &[Complex<R>], ldb: usize, beta: Complex<R>, c: &mut [Complex<R>], ldc: usize)
+where
+    R: Scalar,
+    Complex<R>: Scalar,
+{
+    if m == 0 || n == 0 {
+        return;
+    }
+    let op_a = |i, p| op_at(a, transa, lda, i, p);
+    let op_b = |p, j| op_at(b, transb, ldb, p, j);
+    let mut t1 = vec![R::zero(); m * n];
+    let mut t2 = vec![R::zero(); m * n];
+    let mut t3 = vec![R::zero(); m * n];
+    if alpha != Complex::<R>::zero() && k > 0 {
+        gemm_blocked(m, n, k, R::one(), |i, p| op_a(i, p).re, |p, j| op_b(p, j).re, R::zero(), &mut t1, m);
+        gemm_blocked(m, n, k, R::one(), |i, p| op_a(i, p).im, |p, j| op_b(p, j).im, R::zero(), &mut t2, m);
+        gemm_blocked(m, n, k, R::one(), |i, p| { let v = op_a(i, p); v.re + v.im }, |p, j| { let v = op_b(p, j); v.re + v.im }, R::zero(), &mut t3, m);
+    }
+    c
+    .par_chunks_mut(ldc)
+    .take(n)
+    .zip(t1.par_chunks(m))
+    .zip(t2.par_chunks(m))
+    .zip(t3.par_chunks(m))
+    .for_each(|(((c_col, t1), t2), t3)| {
+        for (((c, &t1), &t2), &t3) in c_col[..m].iter_mut().zip(t1).zip(t2).zip(t3) {
+            let ab = alpha * Complex::new(t1 - t2, t3 - t1 - t2);
+            *c = if beta == Complex::<R>::zero() { ab } else { ab + beta * *c };
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level3::gemm::Gemm;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_gemm3m() {
+        // [[1+i, i], [2, 1-i]] * [1, i]
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 1.0), Complex::new(1.0, -1.0)];
+        let b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f32: Vec<Complex<f32>> = vec![Complex::new(f32::NAN, 0.0); 2];
+        // [[1-i, 2], [-i, 1+i]] * [1, i] * i + [1, 1]
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 1.0), Complex::new(1.0, -1.0)];
+        let b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0); 2];
+
+        a_complex_f32.as_slice().gemm3m(Trans::NoTrans, Trans::NoTrans, 2, 1, 2, Complex::new(1.0, 0.0), 2, &b_complex_f32, 2, Complex::new(0.0, 0.0), &mut c_complex_f32, 2);
+        assert_eq!(c_complex_f32, vec![Complex::new(0.0, 1.0), Complex::new(3.0, 1.0)]);
+
+        a_complex_f64.as_slice().gemm3m(Trans::ConjTrans, Trans::NoTrans, 2, 1, 2, Complex::new(0.0, 1.0), 2, &b_complex_f64, 2, Complex::new(1.0, 0.0), &mut c_complex_f64, 2);
+        assert_eq!(c_complex_f64, vec![Complex::new(0.0, 1.0), Complex::new(1.0, -1.0)]);
+
+        // Against the 4M product over every blocking boundary: the two agree to within a few
+        // ulps of the size of the terms summed, which bounds the 3M cancellation error
+        let (m, n, k) = (133, 70, 300);
+        let a: Vec<Complex<f64>> = (0..m * k).map(|v| Complex::new(((v * 7) % 13) as f64 / 3.0 - 2.0, ((v * 3) % 11) as f64 / 7.0 - 0.7)).collect();
+        let b: Vec<Complex<f64>> = (0..k * n).map(|v| Complex::new(((v * 5) % 11) as f64 / 9.0 - 0.5, ((v * 2) % 13) as f64 / 5.0 - 1.2)).collect();
+        let c: Vec<Complex<f64>> = (0..m * n).map(|v| Complex::new((v % 3) as f64, (v % 5) as f64)).collect();
+        let alpha = Complex::new(0.5, -1.5);
+        let beta = Complex::new(-1.0, 0.25);
+        for (transa, transb) in [(Trans::NoTrans, Trans::NoTrans), (Trans::ConjTrans, Trans::Trans)] {
+            let lda = if transa == Trans::NoTrans { m } else { k };
+            let ldb = if transb == Trans::NoTrans { k } else { n };
+            let mut c4m = c.clone();
+            let mut c3m = c.clone();
+            a.as_slice().gemm(transa, transb, m, n, k, alpha, lda, &b, ldb, beta, &mut c4m, m);
+            a.as_slice().gemm3m(transa, transb, m, n, k, alpha, lda, &b, ldb, beta, &mut c3m, m);
+            let scale: f64 = k as f64 * 4.0 * 4.0 * alpha.norm();
+            for (x, y) in c3m.iter().zip(&c4m) {
+                assert!((x - y).norm() < 8.0 * f64::EPSILON * scale);
+            }
+        }
+
+        // A product whose imaginary part cancels to zero: 4M gets it exactly, 3M only to
+        // within the magnitude of the real terms
+        let a_big: Vec<Complex<f32>> = vec![Complex::new(1.0e4, 1.0e-4)];
+        let b_big: Vec<Complex<f32>> = vec![Complex::new(1.0e4, -1.0e-4)];
+        let mut c4m = vec![Complex::new(0.0f32, 0.0)];
+        let mut c3m = vec![Complex::new(0.0f32, 0.0)];
+        a_big.as_slice().gemm(Trans::NoTrans, Trans::NoTrans, 1, 1, 1, Complex::new(1.0, 0.0), 1, &b_big, 1, Complex::new(0.0, 0.0), &mut c4m, 1);
+        a_big.as_slice().gemm3m(Trans::NoTrans, Trans::NoTrans, 1, 1, 1, Complex::new(1.0, 0.0), 1, &b_big, 1, Complex::new(0.0, 0.0), &mut c3m, 1);
+        assert_eq!(c4m[0].im, 0.0);
+        assert_eq!(c3m[0].re, c4m[0].re);
+        assert!(c3m[0].im.abs() <= 8.0 * f32::EPSILON * 1.0e8);
+    }
+}
@@ -0,0 +1,131 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::level3::gemm::gemm_serial;
+use crate::scalar::Scalar;
+use crate::types::{op_at, Trans};
+
+// Shared parameters of `size` consecutive products in a batch, as in the grouped
+// `cblas_?gemm_batch` interface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GemmGroup<T> {
+    pub transa: Trans,
+    pub transb: Trans,
+    pub m: usize,
+    pub n: usize,
+    pub k: usize,
+    pub alpha: T,
+    pub lda: usize,
+    pub ldb: usize,
+    pub beta: T,
+    pub ldc: usize,
+    pub size: usize,
+}
+
+pub trait GemmBatch {
+    type Output;
+    fn gemm_batch(&self, groups: &[GemmGroup<Self::Output>], b: &Self, c: &mut [&mut [Self::Output]]);
+}
+
+impl GemmBatch for [&[f32]] {
+    type Output = f32;
+    fn gemm_batch(&self, groups: &[GemmGroup<f32>], b: &Self, c: &mut [&mut [f32]]) {
+        gemm_batch(self, groups, b, c)
+    }
+}
+impl GemmBatch for [&[f64]] {
+    type Output = f64;
+    fn gemm_batch(&self, groups: &[GemmGroup<f64>], b: &Self, c: &mut [&mut [f64]]) {
+        gemm_batch(self, groups, b, c)
+    }
+}
+impl GemmBatch for [&[Complex<f32>]] {
+    type Output = Complex<f32>;
+    fn gemm_batch(&self, groups: &[GemmGroup<Complex<f32>>], b: &Self, c: &mut [&mut [Complex<f32>]]) {
+        gemm_batch(self, groups, b, c)
+    }
+}
+impl GemmBatch for [&[Complex<f64>]] {
+    type Output = Complex<f64>;
+    fn gemm_batch(&self, groups: &[GemmGroup<Complex<f64>>], b: &Self, c: &mut [&mut [Complex<f64>]]) {
+        gemm_batch(self, groups, b, c)
+    }
+}
+
+// c[i] = alpha * op(a[i]) * op(b[i]) + beta * c[i], with the parameters of the group that
+// entry i falls in. The products are spread over the thread pool, each computed on a
+// single thread.
+fn gemm_batch<T: Scalar>(a: &[&[T]], groups: &[GemmGroup<T>], b: &[&[T]], c: &mut [&mut [T]]) {
+    let count: usize = groups.iter().map(|g| g.size).sum();
+    assert_eq!(a.len(), count, "a must hold one matrix for every product in the groups");
+    assert_eq!(b.len(), count, "b must hold one matrix for every product in the groups");
+    assert_eq!(c.len(), count, "c must hold one matrix for every product in the groups");
+    let params: Vec<&GemmGroup<T>> = groups
+    .iter()
+    .flat_map(|g| std::iter::repeat_n(g, g.size))
+    .collect();
+    c
+    .par_iter_mut()
+    .zip(a)
+    .zip(b)
+    .zip(params)
+    .for_each(|(((c, a), b), g)| {
+        gemm_serial(g.m, g.n, g.k, g.alpha, |i, p| op_at(a, g.transa, g.lda, i, p), |p, j| op_at(b, g.transb, g.ldb, p, j), g.beta, c, g.ldc)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level3::gemm::Gemm;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_gemm_batch() {
+        // One 2 x 2 group of two products and one 1 x 1 group
+        let a0_f32: Vec<f32> = vec![1.0, 3.0, 2.0, 4.0];
+        let a1_f32: Vec<f32> = vec![1.0, 0.0, 0.0, 1.0];
+        let a2_f32: Vec<f32> = vec![3.0];
+        let b0_f32: Vec<f32> = vec![5.0, 7.0, 6.0, 8.0];
+        let b2_f32: Vec<f32> = vec![4.0];
+        let mut c0_f32: Vec<f32> = vec![0.0; 4];
+        let mut c1_f32: Vec<f32> = vec![1.0; 4];
+        let mut c2_f32: Vec<f32> = vec![1.0];
+        let groups_f32 = [
+            GemmGroup { transa: Trans::NoTrans, transb: Trans::NoTrans, m: 2, n: 2, k: 2, alpha: 1.0, lda: 2, ldb: 2, beta: 1.0, ldc: 2, size: 2 },
+            GemmGroup { transa: Trans::NoTrans, transb: Trans::NoTrans, m: 1, n: 1, k: 1, alpha: 2.0, lda: 1, ldb: 1, beta: 0.0, ldc: 1, size: 1 },
+        ];
+        // [[1+i, 0], [0, 1]]^H * [[1, 0], [i, 1]]
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+        let b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 4];
+        let groups_complex_f64 = [GemmGroup { transa: Trans::ConjTrans, transb: Trans::NoTrans, m: 2, n: 2, k: 2, alpha: Complex::new(1.0, 0.0), lda: 2, ldb: 2, beta: Complex::new(0.0, 0.0), ldc: 2, size: 1 }];
+
+        [a0_f32.as_slice(), &a1_f32, &a2_f32].gemm_batch(&groups_f32, &[&b0_f32, &b0_f32, &b2_f32], &mut [&mut c0_f32, &mut c1_f32, &mut c2_f32]);
+        assert_eq!(c0_f32, vec![19.0, 43.0, 22.0, 50.0]);
+        assert_eq!(c1_f32, vec![6.0, 8.0, 7.0, 9.0]);
+        assert_eq!(c2_f32, vec![24.0]);
+
+        [a_complex_f64.as_slice()].gemm_batch(&groups_complex_f64, &[&b_complex_f64], &mut [&mut c_complex_f64]);
+        assert_eq!(c_complex_f64, vec![Complex::new(1.0, -1.0), Complex::new(0.0, 1.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)]);
+
+        // Many products of mixed shapes, each against a single GEMM call
+        let shapes = [(3, 5, 2), (17, 9, 33), (8, 8, 8)];
+        let a: Vec<Vec<f64>> = (0..60).map(|e| (0..17 * 33).map(|v| ((v * 7 + e) % 13) as f64 - 6.0).collect()).collect();
+        let b: Vec<Vec<f64>> = (0..60).map(|e| (0..33 * 9).map(|v| ((v * 5 + e) % 11) as f64 - 5.0).collect()).collect();
+        let mut c: Vec<Vec<f64>> = vec![vec![1.0; 17 * 9]; 60];
+        let mut expected = c.clone();
+        let groups: Vec<GemmGroup<f64>> = shapes
+        .iter()
+        .map(|&(m, n, k)| GemmGroup { transa: Trans::NoTrans, transb: Trans::Trans, m, n, k, alpha: 2.0, lda: m, ldb: n, beta: -1.0, ldc: m, size: 20 })
+        .collect();
+        for (e, expected) in expected.iter_mut().enumerate() {
+            let g = &groups[e / 20];
+            a[e].as_slice().gemm(g.transa, g.transb, g.m, g.n, g.k, g.alpha, g.lda, &b[e], g.ldb, g.beta, expected, g.ldc);
+        }
+        let a_refs: Vec<&[f64]> = a.iter().map(|a| a.as_slice()).collect();
+        let b_refs: Vec<&[f64]> = b.iter().map(|b| b.as_slice()).collect();
+        let mut c_refs: Vec<&mut [f64]> = c.iter_mut().map(|c| c.as_mut_slice()).collect();
+        a_refs.as_slice().gemm_batch(&groups, &b_refs, &mut c_refs);
+        assert_eq!(c, expected);
+    }
+}
@@ -0,0 +1,69 @@
+use half::{bf16, f16};
+use crate::level3::gemm::gemm_blocked;
+use crate::types::Trans;
+
+// GEMM on half-precision A and B with single-precision accumulation and C, in the manner of
+// `cublasGemmEx` with 16-bit inputs and a 32-bit compute type. Elements are widened to f32
+// as they are packed, so the inner loops are those of the f32 GEMM and no widened copy of
+// A or B is ever held in full.
+pub trait GemmEx {
+    type Output;
+    fn gemm_ex(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Self::Output, lda: usize, b: &Self, ldb: usize, beta: Self::Output, c: &mut [Self::Output], ldc: usize);
+}
+
+impl GemmEx for [bf16] {
+    type Output = f32;
+    fn gemm_ex(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: f32, lda: usize, b: &Self, ldb: usize, beta: f32, c: &mut [f32], ldc: usize) {
+        gemm_ex(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+impl GemmEx for [f16] {
+    type Output = f32;
+    fn gemm_ex(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: f32, lda: usize, b: &Self, ldb: usize, beta: f32, c: &mut [f32], ldc: usize) {
+        gemm_ex(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+
+fn gemm_ex<H: Copy + Sync + Into<f32>>(a: &[H], transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: f32, lda: usize, b: &[H], ldb: usize, beta: f32, c: &mut [f32], ldc: usize) {
+    gemm_blocked(m, n, k, alpha, |i, p| widened_at(a, transa, lda, i, p), |p, j| widened_at(b, transb, ldb, p, j), beta, c, ldc)
+}
+
+// Element (i, j) of op(A) as f32; the inputs are real, so ConjTrans is Trans.
+fn widened_at<H: Copy + Into<f32>>(a: &[H], trans: Trans, ld: usize, i: usize, j: usize) -> f32 {
+    match trans {
+        Trans::NoTrans => a[i + j * ld].into(),
+        Trans::Trans | Trans::ConjTrans => a[j + i * ld].into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gemm_ex() {
+        // A = [[1, 2, 3], [4, 5, 6]], B = [[1, 0], [0, 1], [1, 1]]
+        let a_bf16: Vec<bf16> = [1.0, 4.0, 2.0, 5.0, 3.0, 6.0].map(bf16::from_f32).to_vec();
+        let b_bf16: Vec<bf16> = [1.0, 0.0, 1.0, 0.0, 1.0, 1.0].map(bf16::from_f32).to_vec();
+        let mut c_bf16: Vec<f32> = vec![1.0; 4];
+        // A^T stored 3 x 2 with lda = 4, B^T stored 2 x 3
+        let a_f16: Vec<f16> = [1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0].map(f16::from_f32).to_vec();
+        let b_f16: Vec<f16> = [1.0, 0.0, 0.0, 1.0, 1.0, 1.0].map(f16::from_f32).to_vec();
+        let mut c_f16: Vec<f32> = vec![f32::NAN; 4];
+
+        a_bf16.as_slice().gemm_ex(Trans::NoTrans, Trans::NoTrans, 2, 2, 3, 1.0, 2, &b_bf16, 3, 2.0, &mut c_bf16, 2);
+        assert_eq!(c_bf16, vec![6.0, 12.0, 7.0, 13.0]);
+
+        a_f16.as_slice().gemm_ex(Trans::Trans, Trans::Trans, 2, 2, 3, 2.0, 4, &b_f16, 2, 0.0, &mut c_f16, 2);
+        assert_eq!(c_f16, vec![8.0, 20.0, 10.0, 22.0]);
+
+        // Accumulation is in f32: 4096 products of 1 + 2^-7 sum past what a half-precision
+        // accumulator could hold exactly
+        let k = 4096;
+        let a: Vec<bf16> = vec![bf16::from_f32(1.0 + 1.0 / 128.0); k];
+        let b: Vec<bf16> = vec![bf16::from_f32(1.0); k];
+        let mut c = vec![0.0f32];
+        a.as_slice().gemm_ex(Trans::NoTrans, Trans::NoTrans, 1, 1, k, 1.0, 1, &b, k, 0.0, &mut c, 1);
+        assert_eq!(c, vec![k as f32 * (1.0 + 1.0 / 128.0)]);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::level3::gemm::gemm_blocked;
+use crate::types::Trans;
+
+// Zero point of a quantized operand: one for the whole tensor, or one per channel, where
+// the channels are the rows of op(A) and the columns of op(B).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ZeroPoint<'a> {
+    PerTensor(i32),
+    PerChannel(&'a [i32]),
+}
+
+impl ZeroPoint<'_> {
+    fn at(&self, channel: usize) -> i32 {
+        match self {
+            ZeroPoint::PerTensor(z) => *z,
+            ZeroPoint::PerChannel(z) => z[channel],
+        }
+    }
+}
+
+pub trait GemmI8 {
+    fn gemm_i8(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, a_zero: ZeroPoint, lda: usize, b: &[i8], b_zero: ZeroPoint, ldb: usize, beta: i32, c: &mut [i32], ldc: usize);
+}
+
+impl GemmI8 for [u8] {
+    fn gemm_i8(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, a_zero: ZeroPoint, lda: usize, b: &[i8], b_zero: ZeroPoint, ldb: usize, beta: i32, c: &mut [i32], ldc: usize) {
+        gemm_i8(self, transa, transb, m, n, k, a_zero, lda, b, b_zero, ldb, beta, c, ldc)
+    }
+}
+impl GemmI8 for [i8] {
+    fn gemm_i8(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, a_zero: ZeroPoint, lda: usize, b: &[i8], b_zero: ZeroPoint, ldb: usize, beta: i32, c: &mut [i32], ldc: usize) {
+        gemm_i8(self, transa, transb, m, n, k, a_zero, lda, b, b_zero, ldb, beta, c, ldc)
+    }
+}
+
+// C = (op(A) - a_zero) * (op(B) - b_zero) + beta * C in i32. The zero points are subtracted
+// as the operands are widened during packing, so the product is exact and the inner loops
+// are plain i32 multiply-adds. As with any i32 accumulation, k must be small enough that
+// the sums cannot overflow. When every zero point lies in the range of its operand, each
+// widened element is at most 255 in magnitude and k * 255 * 255 < 2^31 suffices. Zero
+// points, per-channel ones included, are arbitrary i32 though, and larger ones shrink the
+// bound on k accordingly.
+fn gemm_i8<A: Copy + Sync + Into<i32>>(a: &[A], transa: Trans, transb: Trans, m: usize, n: usize, k: usize, a_zero: ZeroPoint, lda: usize, b: &[i8], b_zero: ZeroPoint, ldb: usize, beta: i32, c: &mut [i32], ldc: usize) {
+    gemm_blocked(m, n, k, 1, |i, p| widened_at(a, transa, lda, i, p) - a_zero.at(i), |p, j| widened_at(b, transb, ldb, p, j) - b_zero.at(j), beta, c, ldc)
+}
+
+fn widened_at<T: Copy + Into<i32>>(a: &[T], trans: Trans, ld: usize, i: usize, j: usize) -> i32 {
+    match trans {
+        Trans::NoTrans => a[i + j * ld].into(),
+        Trans::Trans | Trans::ConjTrans => a[j + i * ld].into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gemm_i8() {
+        // (A - 128) = [[1, 2], [3, 4]], (B - 0) = [[5, 6], [7, 8]]
+        let a_u8: Vec<u8> = vec![129, 131, 130, 132];
+        let b_u8: Vec<i8> = vec![5, 7, 6, 8];
+        let mut c_u8: Vec<i32> = vec![-1; 4];
+        // Per-channel: rows of A offset by [1, -1], columns of B by [0, 2]; A^T is stored
+        let a_i8: Vec<i8> = vec![2, 3, 2, 3];
+        let b_i8: Vec<i8> = vec![5, 7, 8, 10];
+        let mut c_i8: Vec<i32> = vec![100; 4];
+
+        a_u8.as_slice().gemm_i8(Trans::NoTrans, Trans::NoTrans, 2, 2, 2, ZeroPoint::PerTensor(128), 2, &b_u8, ZeroPoint::PerTensor(0), 2, 0, &mut c_u8, 2);
+        assert_eq!(c_u8, vec![19, 43, 22, 50]);
+
+        // op(A) - za = [[1, 2], [3, 4]], op(B) - zb = [[5, 6], [7, 8]]
+        a_i8.as_slice().gemm_i8(Trans::Trans, Trans::NoTrans, 2, 2, 2, ZeroPoint::PerChannel(&[1, -1]), 2, &b_i8, ZeroPoint::PerChannel(&[0, 2]), 2, 1, &mut c_i8, 2);
+        assert_eq!(c_i8, vec![119, 143, 122, 150]);
+
+        // Extreme values across blocking boundaries, against a reference loop
+        let (m, n, k) = (131, 70, 300);
+        let a: Vec<u8> = (0..m * k).map(|v| ((v * 37) % 256) as u8).collect();
+        let b: Vec<i8> = (0..k * n).map(|v| ((v * 53) % 256) as u8 as i8).collect();
+        let za: Vec<i32> = (0..m).map(|i| (i % 7) as i32 * 40).collect();
+        let mut c = vec![0; m * n];
+        a.as_slice().gemm_i8(Trans::NoTrans, Trans::NoTrans, m, n, k, ZeroPoint::PerChannel(&za), m, &b, ZeroPoint::PerTensor(-3), k, 0, &mut c, m);
+        for j in 0..n {
+            for i in 0..m {
+                let dot: i32 = (0..k).map(|p| (a[i + p * m] as i32 - za[i]) * (b[p + j * k] as i32 + 3)).sum();
+                assert_eq!(c[i + j * m], dot);
+            }
+        }
+    }
+}
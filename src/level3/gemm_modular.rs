@@ -0,0 +1,75 @@
+use crate::level3::gemm::{gemm_blocked, Accumulator, Gemm};
+use crate::modular::Modular;
+use crate::types::Trans;
+
+// Register tiles sum raw products in u128, which holds KC products of residues below 2^32
+// with room to spare, so each element of C is reduced once per KC block.
+impl<const P: u64> Accumulator for Modular<P> {
+    type Acc = u128;
+    fn padding() -> Self {
+        Modular::zero()
+    }
+    fn is_zero(self) -> bool {
+        self == Modular::zero()
+    }
+    fn is_one(self) -> bool {
+        self == Modular::one()
+    }
+    fn acc_zero() -> u128 {
+        0
+    }
+    fn mul_add(acc: &mut u128, a: Self, b: Self) {
+        *acc += a.raw_mul(b);
+    }
+    fn scaled(self, beta: Self) -> Self {
+        beta * self
+    }
+    fn add_scaled(&mut self, alpha: Self, acc: u128) {
+        *self += alpha * Modular::reduce(acc);
+    }
+}
+
+impl<const P: u64> Gemm for [Modular<P>] {
+    type Output = Modular<P>;
+    fn gemm(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Modular<P>, lda: usize, b: &Self, ldb: usize, beta: Modular<P>, c: &mut Self, ldc: usize) {
+        gemm_modular(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+
+// GEMM over the integers mod P on the blocked driver; ConjTrans is Trans.
+fn gemm_modular<const P: u64>(a: &[Modular<P>], transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Modular<P>, lda: usize, b: &[Modular<P>], ldb: usize, beta: Modular<P>, c: &mut [Modular<P>], ldc: usize) {
+    let a_at = |i: usize, p: usize| if transa == Trans::NoTrans { a[i + p * lda] } else { a[p + i * lda] };
+    let b_at = |p: usize, j: usize| if transb == Trans::NoTrans { b[p + j * ldb] } else { b[j + p * ldb] };
+    gemm_blocked(m, n, k, alpha, a_at, b_at, beta, c, ldc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gemm_modular() {
+        // Over GF(7): [[6, 6], [6, 6]] * [[6, 6], [6, 6]] = 2 everywhere, plus 3 * C
+        let a_small: Vec<Modular<7>> = vec![Modular::new(6); 4];
+        let mut c_small: Vec<Modular<7>> = vec![Modular::new(1); 4];
+        a_small.as_slice().gemm(Trans::NoTrans, Trans::ConjTrans, 2, 2, 2, Modular::new(1), 2, &a_small, 2, Modular::new(3), &mut c_small, 2);
+        assert_eq!(c_small, vec![Modular::new(5); 4]);
+
+        // Exact over the largest prime below 2^32 across every blocking boundary, against a
+        // reference loop
+        const P: u64 = 4294967291;
+        let (m, n, k) = (261, 70, 300);
+        let a: Vec<Modular<P>> = (0..m * k).map(|v| Modular::new(P - 1 - (v as u64 * 7919) % 1000)).collect();
+        let b: Vec<Modular<P>> = (0..k * n).map(|v| Modular::new(P - 1 - (v as u64 * 104729) % 1000)).collect();
+        let mut c: Vec<Modular<P>> = (0..m * n).map(|v| Modular::new(v as u64)).collect();
+        let mut expected = c.clone();
+        for j in 0..n {
+            for i in 0..m {
+                let dot: u128 = (0..k).map(|p| a[i + p * m].value() as u128 * b[j + p * n].value() as u128).sum();
+                expected[i + j * m] = Modular::new(3) * Modular::new((dot % P as u128) as u64) + Modular::new(5) * expected[i + j * m];
+            }
+        }
+        a.as_slice().gemm(Trans::NoTrans, Trans::Trans, m, n, k, Modular::new(3), m, &b, n, Modular::new(5), &mut c, m);
+        assert_eq!(c, expected);
+    }
+}
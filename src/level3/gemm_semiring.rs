@@ -0,0 +1,119 @@
+use crate::level3::gemm::{gemm_blocked, Accumulator};
+use crate::semiring::{MaxPlus, MaxTimes, MinPlus, OrAnd, Semiring};
+use crate::types::Trans;
+
+pub trait GemmSemiring {
+    type Output;
+    fn gemm_semiring(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Self::Output, lda: usize, b: &Self, ldb: usize, beta: Self::Output, c: &mut Self, ldc: usize);
+}
+
+// Semiring elements run on the blocked driver with `add` and `mul` in place of + and *. Tiles
+// accumulate in the element type, and a beta equal to the semiring's zero overwrites C.
+// A blanket impl over `Semiring` would conflict with the one over `Scalar`, so each concrete
+// semiring gets its own.
+macro_rules! impl_accumulator {
+    ($($s:ty),*) => {$(
+        impl Accumulator for $s {
+            type Acc = Self;
+            fn padding() -> Self {
+                Self::zero()
+            }
+            fn is_zero(self) -> bool {
+                self == Self::zero()
+            }
+            fn is_one(self) -> bool {
+                self == Self::one()
+            }
+            fn acc_zero() -> Self {
+                Self::zero()
+            }
+            fn mul_add(acc: &mut Self, a: Self, b: Self) {
+                *acc = acc.add(a.mul(b));
+            }
+            fn scaled(self, beta: Self) -> Self {
+                if beta == Self::zero() { Self::zero() } else { beta.mul(self) }
+            }
+            fn add_scaled(&mut self, alpha: Self, acc: Self) {
+                *self = self.add(alpha.mul(acc));
+            }
+        }
+
+        impl GemmSemiring for [$s] {
+            type Output = $s;
+            fn gemm_semiring(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: $s, lda: usize, b: &Self, ldb: usize, beta: $s, c: &mut Self, ldc: usize) {
+                gemm_semiring(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc)
+            }
+        }
+    )*};
+}
+impl_accumulator!(MinPlus<f32>, MinPlus<f64>, MaxPlus<f32>, MaxPlus<f64>, MaxTimes<f32>, MaxTimes<f64>, OrAnd);
+
+// C = alpha * op(A) * op(B) + beta * C with the semiring's `mul` and `add` in place of * and
+// +, on the blocked driver; ConjTrans is Trans.
+fn gemm_semiring<S: Semiring + Accumulator>(a: &[S], transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: S, lda: usize, b: &[S], ldb: usize, beta: S, c: &mut [S], ldc: usize) {
+    let a_at = |i: usize, p: usize| if transa == Trans::NoTrans { a[i + p * lda] } else { a[p + i * lda] };
+    let b_at = |p: usize, j: usize| if transb == Trans::NoTrans { b[p + j * ldb] } else { b[j + p * ldb] };
+    gemm_blocked(m, n, k, alpha, a_at, b_at, beta, c, ldc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gemm_semiring() {
+        let inf = f64::INFINITY;
+        // Edge weights of 0 -> 1 (1), 1 -> 2 (2), 0 -> 2 (5), column-major with D(i, j) the
+        // weight of i -> j; two rounds of D = D * D give all shortest paths
+        let mut d: Vec<MinPlus<f64>> = [0.0, inf, inf, 1.0, 0.0, inf, 5.0, 2.0, 0.0].map(MinPlus).to_vec();
+        for _ in 0..2 {
+            let prev = d.clone();
+            prev.as_slice().gemm_semiring(Trans::NoTrans, Trans::NoTrans, 3, 3, 3, MinPlus::one(), 3, &prev, 3, MinPlus::zero(), &mut d, 3);
+        }
+        assert_eq!(d, [0.0, inf, inf, 1.0, 0.0, inf, 3.0, 2.0, 0.0].map(MinPlus).to_vec());
+
+        // Longest two-step path, max(1 + 3, 2 - 1) = 4, merged into C = 5 with max
+        let a_max_plus: Vec<MaxPlus<f32>> = vec![MaxPlus(1.0), MaxPlus(2.0)];
+        let b_max_plus: Vec<MaxPlus<f32>> = vec![MaxPlus(3.0), MaxPlus(-1.0)];
+        let mut c_max_plus: Vec<MaxPlus<f32>> = vec![MaxPlus(5.0)];
+        a_max_plus.as_slice().gemm_semiring(Trans::NoTrans, Trans::NoTrans, 1, 1, 2, MaxPlus::one(), 1, &b_max_plus, 2, MaxPlus::one(), &mut c_max_plus, 1);
+        assert_eq!(c_max_plus, vec![MaxPlus(5.0)]);
+
+        // Most probable step, through B^T: max(0.5 * 0.2, 0.25 * 0.8) = 0.2
+        let a_max_times: Vec<MaxTimes<f64>> = vec![MaxTimes(0.5), MaxTimes(0.25)];
+        let b_max_times: Vec<MaxTimes<f64>> = vec![MaxTimes(0.2), MaxTimes(0.8)];
+        let mut c_max_times: Vec<MaxTimes<f64>> = vec![MaxTimes(f64::NAN)];
+        a_max_times.as_slice().gemm_semiring(Trans::NoTrans, Trans::Trans, 1, 1, 2, MaxTimes::one(), 1, &b_max_times, 1, MaxTimes::zero(), &mut c_max_times, 1);
+        assert_eq!(c_max_times, vec![MaxTimes(0.2)]);
+
+        // Reachability on a 150-node path graph i -> i + 1 across blocking boundaries: after
+        // squaring the reflexive adjacency matrix k times, j is reachable from i iff
+        // 0 <= j - i <= 2^k
+        let n = 150;
+        let mut r: Vec<OrAnd> = (0..n * n).map(|v| OrAnd(v % n == v / n || v % n + 1 == v / n)).collect();
+        for _ in 0..3 {
+            let prev = r.clone();
+            prev.as_slice().gemm_semiring(Trans::NoTrans, Trans::NoTrans, n, n, n, OrAnd::one(), n, &prev, n, OrAnd::zero(), &mut r, n);
+        }
+        for j in 0..n {
+            for i in 0..n {
+                assert_eq!(r[i + j * n], OrAnd(i <= j && j - i <= 8));
+            }
+        }
+
+        // Min-plus against a reference loop over every blocking boundary
+        let (m, n, k) = (261, 70, 300);
+        let a: Vec<MinPlus<f64>> = (0..k * m).map(|v| MinPlus(((v * 7) % 13) as f64)).collect();
+        let b: Vec<MinPlus<f64>> = (0..k * n).map(|v| MinPlus(((v * 5) % 11) as f64)).collect();
+        let mut c: Vec<MinPlus<f64>> = (0..m * n).map(|v| MinPlus((v % 5) as f64)).collect();
+        let mut expected = c.clone();
+        for j in 0..n {
+            for i in 0..m {
+                let best = (0..k).map(|p| a[p + i * k].0 + b[p + j * k].0).fold(expected[i + j * m].0, f64::min);
+                expected[i + j * m] = MinPlus(best);
+            }
+        }
+        a.as_slice().gemm_semiring(Trans::Trans, Trans::NoTrans, m, n, k, MinPlus::one(), k, &b, k, MinPlus::one(), &mut c, m);
+        assert_eq!(c, expected);
+    }
+}
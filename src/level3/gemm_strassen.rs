@@ -0,0 +1,305 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::level3::gemm::gemm_blocked;
+use crate::scalar::Scalar;
+use crate::types::{op_at, Trans};
+
+// Products are only split while every dimension of the halves stays at least this large;
+// below it the blocked GEMM is faster than another level of recursion.
+const CUTOFF: usize = 512;
+
+// Strassen-Winograd GEMM: each level of recursion replaces eight half-size products by seven
+// and fifteen additions, so a product split d times does (7/8)^d of the multiplications of
+// `Gemm::gemm`. The rounding differs from the standard product: the error is bounded in
+// norm rather than element by element, with a constant that grows with each level, so
+// entries of C much smaller than the rest may lose relative accuracy. Memory use is also
+// higher, at roughly 7.5 * m * n elements of workspace for a square product.
+pub trait GemmStrassen {
+    type Output;
+    fn gemm_strassen(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Self::Output, lda: usize, b: &Self, ldb: usize, beta: Self::Output, c: &mut Self, ldc: usize);
+}
+
+impl GemmStrassen for [f32] {
+    type Output = f32;
+    fn gemm_strassen(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: f32, lda: usize, b: &Self, ldb: usize, beta: f32, c: &mut Self, ldc: usize) {
+        gemm_strassen(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc, CUTOFF)
+    }
+}
+impl GemmStrassen for [f64] {
+    type Output = f64;
+    fn gemm_strassen(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: f64, lda: usize, b: &Self, ldb: usize, beta: f64, c: &mut Self, ldc: usize) {
+        gemm_strassen(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc, CUTOFF)
+    }
+}
+impl GemmStrassen for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn gemm_strassen(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Complex<f32>, lda: usize, b: &Self, ldb: usize, beta: Complex<f32>, c: &mut Self, ldc: usize) {
+        gemm_strassen(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc, CUTOFF)
+    }
+}
+impl GemmStrassen for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn gemm_strassen(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Complex<f64>, lda: usize, b: &Self, ldb: usize, beta: Complex<f64>, c: &mut Self, ldc: usize) {
+        gemm_strassen(self, transa, transb, m, n, k, alpha, lda, b, ldb, beta, c, ldc, CUTOFF)
+    }
+}
+
+// C = alpha * op(A) * op(B) + beta * C. op(A) and op(B) are copied, zero-padded so each
+// dimension halves evenly `depth` times, into a recursive quadrant layout: a matrix split
+// d more times is stored as its quadrants 11, 21, 12, 22 one after another, each laid out
+// the same way, down to column-major blocks at d = 0. Every quadrant is then a contiguous
+// slice. The product is formed in the same layout and merged into C at the end.
+fn gemm_strassen<T: Scalar>(a: &[T], transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: T, lda: usize, b: &[T], ldb: usize, beta: T, c: &mut [T], ldc: usize, cutoff: usize) {
+    let mut depth = 0;
+    while m.min(n).min(k) >> (depth + 1) >= cutoff.max(1) {
+        depth += 1;
+    }
+    if depth == 0 || alpha == T::zero() {
+        gemm_blocked(m, n, k, alpha, |i, p| op_at(a, transa, lda, i, p), |p, j| op_at(b, transb, ldb, p, j), beta, c, ldc);
+        return;
+    }
+    let (mp, np, kp) = (padded(m, depth), padded(n, depth), padded(k, depth));
+    let mut a_blocks = vec![T::zero(); mp * kp];
+    let mut b_blocks = vec![T::zero(); kp * np];
+    let mut p_blocks = vec![T::zero(); mp * np];
+    pack(&mut a_blocks, mp, kp, depth, |i, p| if i < m && p < k { op_at(a, transa, lda, i, p) } else { T::zero() });
+    pack(&mut b_blocks, kp, np, depth, |p, j| if p < k && j < n { op_at(b, transb, ldb, p, j) } else { T::zero() });
+    let mut ws = vec![T::zero(); parallel_workspace(mp, np, kp, depth)];
+    winograd_parallel(&a_blocks, &b_blocks, &mut p_blocks, mp, np, kp, depth, &mut ws);
+    c
+    .par_chunks_mut(ldc)
+    .take(n)
+    .enumerate()
+    .for_each(|(j, c_col)| {
+        for (i, c) in c_col[..m].iter_mut().enumerate() {
+            let ab = alpha * p_blocks[block_index(i, j, mp, np, depth)];
+            *c = if beta == T::zero() { ab } else { ab + beta * *c };
+        }
+    })
+}
+
+// `len` rounded up so it splits evenly `depth` times.
+fn padded(len: usize, depth: usize) -> usize {
+    len.div_ceil(1 << depth) << depth
+}
+
+// Offset of element (i, j) of a rows x cols matrix in the quadrant layout.
+fn block_index(mut i: usize, mut j: usize, mut rows: usize, mut cols: usize, depth: usize) -> usize {
+    let mut offset = 0;
+    for _ in 0..depth {
+        rows /= 2;
+        cols /= 2;
+        let (qi, qj) = (i / rows, j / cols);
+        offset += (qi + 2 * qj) * rows * cols;
+        i -= qi * rows;
+        j -= qj * cols;
+    }
+    offset + i + j * rows
+}
+
+// Fills a rows x cols matrix in the quadrant layout from `at`, in parallel.
+fn pack<T: Scalar, F: Fn(usize, usize) -> T + Sync>(dst: &mut [T], rows: usize, cols: usize, depth: usize, at: F) {
+    let (leaf_rows, leaf_cols) = (rows >> depth, cols >> depth);
+    dst
+    .par_chunks_mut(leaf_rows * leaf_cols)
+    .enumerate()
+    .for_each(|(leaf, block)| {
+        // The leaf's position: successive base-4 digits of its number pick the quadrants.
+        let (mut i0, mut j0) = (0, 0);
+        for level in 0..depth {
+            let q = (leaf >> (2 * (depth - 1 - level))) & 3;
+            i0 += (q & 1) * (rows >> (level + 1));
+            j0 += (q >> 1) * (cols >> (level + 1));
+        }
+        for (j, col) in block.chunks_mut(leaf_rows).enumerate() {
+            for (i, x) in col.iter_mut().enumerate() {
+                *x = at(i0 + i, j0 + j);
+            }
+        }
+    })
+}
+
+fn quarters<T>(x: &[T]) -> (&[T], &[T], &[T], &[T]) {
+    let q = x.len() / 4;
+    (&x[..q], &x[q..2 * q], &x[2 * q..3 * q], &x[3 * q..])
+}
+
+fn quarters_mut<T>(x: &mut [T]) -> (&mut [T], &mut [T], &mut [T], &mut [T]) {
+    let q = x.len() / 4;
+    let (x11, rest) = x.split_at_mut(q);
+    let (x21, rest) = rest.split_at_mut(q);
+    let (x12, x22) = rest.split_at_mut(q);
+    (x11, x21, x12, x22)
+}
+
+// x = f(a, b) and x = f(x, a), elementwise over matching blocks.
+fn assign<T: Scalar>(x: &mut [T], a: &[T], b: &[T], f: impl Fn(T, T) -> T + Sync) {
+    x
+    .par_iter_mut()
+    .zip(a)
+    .zip(b)
+    .for_each(|((x, &a), &b)| *x = f(a, b))
+}
+
+fn update<T: Scalar>(x: &mut [T], a: &[T], f: impl Fn(T, T) -> T + Sync) {
+    x
+    .par_iter_mut()
+    .zip(a)
+    .for_each(|(x, &a)| *x = f(*x, a))
+}
+
+// Workspace of `winograd_parallel` and of the sequential levels below it: the operand sums
+// of all seven products and three of the products exist at once at the top, while each
+// lower level needs one sum of each operand and one product, reused across its products.
+fn parallel_workspace(m: usize, n: usize, k: usize, depth: usize) -> usize {
+    let (m2, n2, k2) = (m / 2, n / 2, k / 2);
+    4 * m2 * k2 + 4 * k2 * n2 + 3 * m2 * n2 + 7 * sequential_workspace(m2, n2, k2, depth - 1)
+}
+
+fn sequential_workspace(m: usize, n: usize, k: usize, depth: usize) -> usize {
+    if depth == 0 {
+        return 0;
+    }
+    let (m2, n2, k2) = (m / 2, n / 2, k / 2);
+    m2 * k2 + k2 * n2 + m2 * n2 + sequential_workspace(m2, n2, k2, depth - 1)
+}
+
+// C = A * B for blocked m x k A and k x n B, with the seven half-size products computed in
+// parallel, each by a sequential recursion with its own slice of the workspace.
+fn winograd_parallel<T: Scalar>(a: &[T], b: &[T], c: &mut [T], m: usize, n: usize, k: usize, depth: usize, ws: &mut [T]) {
+    let (m2, n2, k2) = (m / 2, n / 2, k / 2);
+    let (a11, a21, a12, a22) = quarters(a);
+    let (b11, b21, b12, b22) = quarters(b);
+    let (c11, c21, c12, c22) = quarters_mut(c);
+    let (s, ws) = ws.split_at_mut(4 * m2 * k2);
+    let (t, ws) = ws.split_at_mut(4 * k2 * n2);
+    let (p, ws) = ws.split_at_mut(3 * m2 * n2);
+    let (s1, s2, s3, s4) = quarters_mut(s);
+    let (t1, t2, t3, t4) = quarters_mut(t);
+    let (p1, rest) = p.split_at_mut(m2 * n2);
+    let (p6, p7) = rest.split_at_mut(m2 * n2);
+    assign(s1, a21, a22, |x, y| x + y);
+    assign(s2, s1, a11, |x, y| x - y);
+    assign(s3, a11, a21, |x, y| x - y);
+    assign(s4, a12, s2, |x, y| x - y);
+    assign(t1, b12, b11, |x, y| x - y);
+    assign(t2, b22, t1, |x, y| x - y);
+    assign(t3, b22, b12, |x, y| x - y);
+    assign(t4, t2, b21, |x, y| x - y);
+    let mut child_ws = ws.chunks_mut(sequential_workspace(m2, n2, k2, depth - 1).max(1));
+    let mut next = || child_ws.next().unwrap_or_default();
+    let (w1, w2, w3, w4, w5, w6, w7) = (next(), next(), next(), next(), next(), next(), next());
+    let (s1, s2, s3, s4, t1, t2, t3, t4) = (&*s1, &*s2, &*s3, &*s4, &*t1, &*t2, &*t3, &*t4);
+    rayon::scope(|scope| {
+        scope.spawn(|_| winograd(a11, b11, p1, m2, n2, k2, depth - 1, w1));
+        scope.spawn(|_| winograd(a12, b21, c11, m2, n2, k2, depth - 1, w2));
+        scope.spawn(|_| winograd(s4, b22, c12, m2, n2, k2, depth - 1, w3));
+        scope.spawn(|_| winograd(a22, t4, c21, m2, n2, k2, depth - 1, w4));
+        scope.spawn(|_| winograd(s1, t1, c22, m2, n2, k2, depth - 1, w5));
+        scope.spawn(|_| winograd(s2, t2, p6, m2, n2, k2, depth - 1, w6));
+        scope.spawn(|_| winograd(s3, t3, p7, m2, n2, k2, depth - 1, w7));
+    });
+    // C11 = P1 + P2, C12 = P1 + P6 + P5 + P3, C21 = P1 + P6 + P7 - P4, C22 = P1 + P6 + P7 + P5
+    update(p6, p1, |x, y| x + y);
+    update(p7, p6, |x, y| x + y);
+    update(c12, p6, |x, y| x + y);
+    update(c12, c22, |x, y| x + y);
+    update(c22, p7, |x, y| x + y);
+    update(c21, p7, |x, y| y - x);
+    update(c11, p1, |x, y| x + y);
+}
+
+// C = A * B in the quadrant layout, one product at a time, following the schedule of
+// Douglas, Heroux, Slishman and Smith (1994) that needs only three temporaries per level:
+// X for sums of A, Y for sums of B and Z for one product. The blocks at depth 0 are
+// multiplied by the blocked GEMM.
+fn winograd<T: Scalar>(a: &[T], b: &[T], c: &mut [T], m: usize, n: usize, k: usize, depth: usize, ws: &mut [T]) {
+    if depth == 0 {
+        gemm_blocked(m, n, k, T::one(), |i, p| a[i + p * m], |p, j| b[p + j * k], T::zero(), c, m);
+        return;
+    }
+    let (m2, n2, k2) = (m / 2, n / 2, k / 2);
+    let (a11, a21, a12, a22) = quarters(a);
+    let (b11, b21, b12, b22) = quarters(b);
+    let (c11, c21, c12, c22) = quarters_mut(c);
+    let (x, ws) = ws.split_at_mut(m2 * k2);
+    let (y, ws) = ws.split_at_mut(k2 * n2);
+    let (z, ws) = ws.split_at_mut(m2 * n2);
+    let add = |x: T, y: T| x + y;
+    let sub = |x: T, y: T| x - y;
+    assign(x, a11, a21, sub);
+    assign(y, b22, b12, sub);
+    winograd(x, y, c21, m2, n2, k2, depth - 1, ws);
+    assign(x, a21, a22, add);
+    assign(y, b12, b11, sub);
+    winograd(x, y, c22, m2, n2, k2, depth - 1, ws);
+    update(x, a11, sub);
+    update(y, b22, |y, b| b - y);
+    winograd(x, y, c12, m2, n2, k2, depth - 1, ws);
+    update(x, a12, |x, a| a - x);
+    winograd(x, b22, c11, m2, n2, k2, depth - 1, ws);
+    winograd(a11, b11, z, m2, n2, k2, depth - 1, ws);
+    update(c12, z, add);
+    update(c21, c12, add);
+    update(c12, c22, add);
+    update(c22, c21, add);
+    update(c12, c11, add);
+    update(y, b21, sub);
+    winograd(a22, y, c11, m2, n2, k2, depth - 1, ws);
+    update(c21, c11, sub);
+    winograd(a12, b21, c11, m2, n2, k2, depth - 1, ws);
+    update(c11, z, add);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level3::gemm::Gemm;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_gemm_strassen() {
+        // Below the cutoff this is the blocked GEMM
+        let a_f32: Vec<f32> = vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0];
+        let b_f32: Vec<f32> = vec![1.0, 0.0, 1.0, 0.0, 1.0, 1.0];
+        let mut c_f32: Vec<f32> = vec![1.0; 4];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, 1.0), Complex::new(1.0, -1.0)];
+        let b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0); 2];
+
+        a_f32.as_slice().gemm_strassen(Trans::NoTrans, Trans::NoTrans, 2, 2, 3, 1.0, 2, &b_f32, 3, 2.0, &mut c_f32, 2);
+        assert_eq!(c_f32, vec![6.0, 12.0, 7.0, 13.0]);
+
+        a_complex_f64.as_slice().gemm_strassen(Trans::ConjTrans, Trans::NoTrans, 2, 1, 2, Complex::new(1.0, 0.0), 2, &b_complex_f64, 2, Complex::new(1.0, 0.0), &mut c_complex_f64, 2);
+        assert_eq!(c_complex_f64, vec![Complex::new(2.0, 1.0), Complex::new(0.0, 0.0)]);
+
+        // With a small cutoff, odd sizes recurse two or three levels with padding. Small
+        // integers keep every intermediate exact, so the result matches the blocked GEMM.
+        for (m, n, k, cutoff) in [(101, 77, 90, 16), (64, 64, 64, 8), (150, 40, 61, 12)] {
+            for (transa, transb) in [(Trans::NoTrans, Trans::NoTrans), (Trans::Trans, Trans::Trans)] {
+                let lda = if transa == Trans::NoTrans { m } else { k };
+                let ldb = if transb == Trans::NoTrans { k } else { n };
+                let a: Vec<f64> = (0..m * k).map(|v| ((v * 7) % 13) as f64 - 6.0).collect();
+                let b: Vec<f64> = (0..k * n).map(|v| ((v * 5) % 11) as f64 - 5.0).collect();
+                let c: Vec<f64> = (0..(m + 3) * n).map(|v| (v % 3) as f64).collect();
+                let mut expected = c.clone();
+                let mut got = c.clone();
+                a.as_slice().gemm(transa, transb, m, n, k, 2.0, lda, &b, ldb, -1.0, &mut expected, m + 3);
+                gemm_strassen(&a, transa, transb, m, n, k, 2.0, lda, &b, ldb, -1.0, &mut got, m + 3, cutoff);
+                assert_eq!(got, expected);
+            }
+        }
+
+        // Complex, to within rounding
+        let (m, n, k) = (70, 66, 75);
+        let a: Vec<Complex<f32>> = (0..m * k).map(|v| Complex::new(((v * 7) % 13) as f32 / 13.0, ((v * 3) % 11) as f32 / 11.0 - 0.5)).collect();
+        let b: Vec<Complex<f32>> = (0..k * n).map(|v| Complex::new(((v * 5) % 11) as f32 / 11.0 - 0.5, ((v * 2) % 13) as f32 / 13.0)).collect();
+        let mut expected = vec![Complex::new(0.0f32, 0.0); m * n];
+        let mut got = vec![Complex::new(f32::NAN, 0.0); m * n];
+        a.as_slice().gemm(Trans::NoTrans, Trans::ConjTrans, m, n, k, Complex::new(1.0, 0.0), m, &b, n, Complex::new(0.0, 0.0), &mut expected, m);
+        gemm_strassen(&a, Trans::NoTrans, Trans::ConjTrans, m, n, k, Complex::new(1.0, 0.0), m, &b, n, Complex::new(0.0, 0.0), &mut got, m, 16);
+        for (x, y) in got.iter().zip(&expected) {
+            assert!((x - y).norm() < 1e-4 * k as f32);
+        }
+    }
+}
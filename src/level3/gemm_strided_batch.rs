@@ -0,0 +1,104 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::level3::gemm::gemm_serial;
+use crate::scalar::Scalar;
+use crate::types::{op_at, Trans};
+
+pub trait GemmStridedBatch {
+    type Output;
+    fn gemm_strided_batch(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Self::Output, lda: usize, stridea: usize, b: &Self, ldb: usize, strideb: usize, beta: Self::Output, c: &mut Self, ldc: usize, stridec: usize, batch_count: usize);
+}
+
+impl GemmStridedBatch for [f32] {
+    type Output = f32;
+    fn gemm_strided_batch(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: f32, lda: usize, stridea: usize, b: &Self, ldb: usize, strideb: usize, beta: f32, c: &mut Self, ldc: usize, stridec: usize, batch_count: usize) {
+        gemm_strided_batch(self, transa, transb, m, n, k, alpha, lda, stridea, b, ldb, strideb, beta, c, ldc, stridec, batch_count)
+    }
+}
+impl GemmStridedBatch for [f64] {
+    type Output = f64;
+    fn gemm_strided_batch(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: f64, lda: usize, stridea: usize, b: &Self, ldb: usize, strideb: usize, beta: f64, c: &mut Self, ldc: usize, stridec: usize, batch_count: usize) {
+        gemm_strided_batch(self, transa, transb, m, n, k, alpha, lda, stridea, b, ldb, strideb, beta, c, ldc, stridec, batch_count)
+    }
+}
+impl GemmStridedBatch for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn gemm_strided_batch(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Complex<f32>, lda: usize, stridea: usize, b: &Self, ldb: usize, strideb: usize, beta: Complex<f32>, c: &mut Self, ldc: usize, stridec: usize, batch_count: usize) {
+        gemm_strided_batch(self, transa, transb, m, n, k, alpha, lda, stridea, b, ldb, strideb, beta, c, ldc, stridec, batch_count)
+    }
+}
+impl GemmStridedBatch for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn gemm_strided_batch(&self, transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: Complex<f64>, lda: usize, stridea: usize, b: &Self, ldb: usize, strideb: usize, beta: Complex<f64>, c: &mut Self, ldc: usize, stridec: usize, batch_count: usize) {
+        gemm_strided_batch(self, transa, transb, m, n, k, alpha, lda, stridea, b, ldb, strideb, beta, c, ldc, stridec, batch_count)
+    }
+}
+
+// C_i = alpha * op(A_i) * op(B_i) + beta * C_i for i < batch_count, where X_i starts at
+// offset i * stridex of its buffer. A stride of zero for A or B reuses one matrix across the
+// batch; the C matrices must not overlap. Each product runs on a single thread.
+fn gemm_strided_batch<T: Scalar>(a: &[T], transa: Trans, transb: Trans, m: usize, n: usize, k: usize, alpha: T, lda: usize, stridea: usize, b: &[T], ldb: usize, strideb: usize, beta: T, c: &mut [T], ldc: usize, stridec: usize, batch_count: usize) {
+    if batch_count == 0 {
+        return;
+    }
+    // Each C_i is split off as its own chunk, so it must fit within one stride.
+    assert!(batch_count == 1 || stridec >= ldc * n, "stridec must be at least ldc * n so the C matrices do not overlap");
+    c
+    .par_chunks_mut(stridec.max(ldc * n).max(1))
+    .take(batch_count)
+    .enumerate()
+    .for_each(|(i, c)| {
+        let a = &a[i * stridea..];
+        let b = &b[i * strideb..];
+        gemm_serial(m, n, k, alpha, |i, p| op_at(a, transa, lda, i, p), |p, j| op_at(b, transb, ldb, p, j), beta, c, ldc)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level3::gemm::Gemm;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_gemm_strided_batch() {
+        // Two 2 x 2 products packed back to back
+        let a_f32: Vec<f32> = vec![1.0, 3.0, 2.0, 4.0, 1.0, 0.0, 0.0, 1.0];
+        let b_f32: Vec<f32> = vec![5.0, 7.0, 6.0, 8.0, 1.0, 2.0, 3.0, 4.0];
+        let mut c_f32: Vec<f32> = vec![0.0; 8];
+        // One A shared by every product (stride 0), C entries padded to stride 3
+        let a_f64: Vec<f64> = vec![2.0];
+        let b_f64: Vec<f64> = vec![1.0, 2.0, 3.0];
+        let mut c_f64: Vec<f64> = vec![1.0, -9.0, -9.0, 1.0, -9.0, -9.0, 1.0];
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(1.0, 1.0)];
+        let b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut c_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 2];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 1.0), Complex::new(1.0, 1.0)];
+        let b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0); 2];
+
+        a_f32.as_slice().gemm_strided_batch(Trans::NoTrans, Trans::NoTrans, 2, 2, 2, 1.0, 2, 4, &b_f32, 2, 4, 0.0, &mut c_f32, 2, 4, 2);
+        assert_eq!(c_f32, vec![19.0, 43.0, 22.0, 50.0, 1.0, 2.0, 3.0, 4.0]);
+
+        a_f64.as_slice().gemm_strided_batch(Trans::NoTrans, Trans::NoTrans, 1, 1, 1, 1.0, 1, 0, &b_f64, 1, 1, 1.0, &mut c_f64, 1, 3, 3);
+        assert_eq!(c_f64, vec![3.0, -9.0, -9.0, 5.0, -9.0, -9.0, 7.0]);
+
+        a_complex_f32.as_slice().gemm_strided_batch(Trans::NoTrans, Trans::NoTrans, 1, 1, 1, Complex::new(1.0, 0.0), 1, 1, &b_complex_f32, 1, 1, Complex::new(0.0, 0.0), &mut c_complex_f32, 1, 1, 2);
+        assert_eq!(c_complex_f32, vec![Complex::new(-1.0, 0.0), Complex::new(2.0, 2.0)]);
+
+        a_complex_f64.as_slice().gemm_strided_batch(Trans::ConjTrans, Trans::NoTrans, 1, 1, 1, Complex::new(1.0, 0.0), 1, 1, &b_complex_f64, 1, 1, Complex::new(1.0, 0.0), &mut c_complex_f64, 1, 1, 2);
+        assert_eq!(c_complex_f64, vec![Complex::new(2.0, 0.0), Complex::new(3.0, -2.0)]);
+
+        // A large batch of small transposed products, each against a single GEMM call
+        let (m, n, k, count) = (7, 5, 9, 500);
+        let a: Vec<f64> = (0..k * m * count).map(|v| ((v * 7) % 13) as f64 - 6.0).collect();
+        let b: Vec<f64> = (0..k * n * count).map(|v| ((v * 5) % 11) as f64 - 5.0).collect();
+        let mut c: Vec<f64> = (0..m * n * count).map(|v| (v % 3) as f64).collect();
+        let mut expected = c.clone();
+        for (i, expected) in expected.chunks_mut(m * n).enumerate() {
+            a[i * k * m..].gemm(Trans::Trans, Trans::NoTrans, m, n, k, 2.0, k, &b[i * k * n..], k, -1.0, expected, m);
+        }
+        a.as_slice().gemm_strided_batch(Trans::Trans, Trans::NoTrans, m, n, k, 2.0, k, k * m, &b, k, k * n, -1.0, &mut c, m, m * n, count);
+        assert_eq!(c, expected);
+    }
+}
@@ -0,0 +1,97 @@
+use num_complex::Complex;
+use crate::level3::gemm::gemm_blocked_triangle;
+use crate::scalar::Scalar;
+use crate::types::{op_at, Trans, Uplo};
+
+pub trait Gemmt {
+    type Output;
+    fn gemmt(&self, uplo: Uplo, transa: Trans, transb: Trans, n: usize, k: usize, alpha: Self::Output, lda: usize, b: &Self, ldb: usize, beta: Self::Output, c: &mut Self, ldc: usize);
+}
+
+impl Gemmt for [f32] {
+    type Output = f32;
+    fn gemmt(&self, uplo: Uplo, transa: Trans, transb: Trans, n: usize, k: usize, alpha: f32, lda: usize, b: &Self, ldb: usize, beta: f32, c: &mut Self, ldc: usize) {
+        gemmt(self, uplo, transa, transb, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+impl Gemmt for [f64] {
+    type Output = f64;
+    fn gemmt(&self, uplo: Uplo, transa: Trans, transb: Trans, n: usize, k: usize, alpha: f64, lda: usize, b: &Self, ldb: usize, beta: f64, c: &mut Self, ldc: usize) {
+        gemmt(self, uplo, transa, transb, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+impl Gemmt for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn gemmt(&self, uplo: Uplo, transa: Trans, transb: Trans, n: usize, k: usize, alpha: Complex<f32>, lda: usize, b: &Self, ldb: usize, beta: Complex<f32>, c: &mut Self, ldc: usize) {
+        gemmt(self, uplo, transa, transb, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+impl Gemmt for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn gemmt(&self, uplo: Uplo, transa: Trans, transb: Trans, n: usize, k: usize, alpha: Complex<f64>, lda: usize, b: &Self, ldb: usize, beta: Complex<f64>, c: &mut Self, ldc: usize) {
+        gemmt(self, uplo, transa, transb, n, k, alpha, lda, b, ldb, beta, c, ldc)
+    }
+}
+
+// C = alpha * op(A) * op(B) + beta * C over the `uplo` triangle of the n x n C, where op(A)
+// is n x k and op(B) is k x n. The other triangle is neither computed nor written.
+fn gemmt<T: Scalar>(a: &[T], uplo: Uplo, transa: Trans, transb: Trans, n: usize, k: usize, alpha: T, lda: usize, b: &[T], ldb: usize, beta: T, c: &mut [T], ldc: usize) {
+    gemm_blocked_triangle(uplo, n, k, alpha, |i, p| op_at(a, transa, lda, i, p), |p, j| op_at(b, transb, ldb, p, j), beta, c, ldc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level3::gemm::Gemm;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_gemmt() {
+        // A = [[1, 2], [3, 4]], B = [[5, 6], [7, 8]], A * B = [[19, 22], [43, 50]]
+        let a_f32: Vec<f32> = vec![1.0, 3.0, 2.0, 4.0];
+        let b_f32: Vec<f32> = vec![5.0, 7.0, 6.0, 8.0];
+        let mut c_f32: Vec<f32> = vec![-1.0; 4];
+        // A^T * B = [[26, 30], [38, 44]]
+        let a_f64: Vec<f64> = vec![1.0, 3.0, 2.0, 4.0];
+        let b_f64: Vec<f64> = vec![5.0, 7.0, 6.0, 8.0];
+        let mut c_f64: Vec<f64> = vec![1.0; 4];
+        // a^H b for a = [i, 1], b = [1, i] as 1 x 2 rows: [[-i, 1], [1, i]]
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)];
+        let b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f32: Vec<Complex<f32>> = vec![Complex::new(9.0, 9.0); 4];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)];
+        let b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(9.0, 9.0); 4];
+
+        a_f32.as_slice().gemmt(Uplo::Upper, Trans::NoTrans, Trans::NoTrans, 2, 2, 1.0, 2, &b_f32, 2, 0.0, &mut c_f32, 2);
+        assert_eq!(c_f32, vec![19.0, -1.0, 22.0, 50.0]);
+
+        a_f64.as_slice().gemmt(Uplo::Lower, Trans::Trans, Trans::NoTrans, 2, 2, 1.0, 2, &b_f64, 2, 1.0, &mut c_f64, 2);
+        assert_eq!(c_f64, vec![27.0, 39.0, 1.0, 45.0]);
+
+        a_complex_f32.as_slice().gemmt(Uplo::Upper, Trans::ConjTrans, Trans::NoTrans, 2, 1, Complex::new(1.0, 0.0), 1, &b_complex_f32, 1, Complex::new(0.0, 0.0), &mut c_complex_f32, 2);
+        assert_eq!(c_complex_f32, vec![Complex::new(0.0, -1.0), Complex::new(9.0, 9.0), Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+
+        a_complex_f64.as_slice().gemmt(Uplo::Lower, Trans::ConjTrans, Trans::NoTrans, 2, 1, Complex::new(1.0, 0.0), 1, &b_complex_f64, 1, Complex::new(0.0, 0.0), &mut c_complex_f64, 2);
+        assert_eq!(c_complex_f64, vec![Complex::new(0.0, -1.0), Complex::new(1.0, 0.0), Complex::new(9.0, 9.0), Complex::new(0.0, 1.0)]);
+
+        // Across several panels and tiles, each triangle matches the full GEMM and the other
+        // triangle keeps its old contents
+        let (n, k) = (301, 45);
+        let a: Vec<f64> = (0..n * k).map(|v| ((v * 7) % 13) as f64 - 6.0).collect();
+        let b: Vec<f64> = (0..k * n).map(|v| ((v * 5) % 11) as f64 - 5.0).collect();
+        let c: Vec<f64> = (0..n * n).map(|v| (v % 9) as f64).collect();
+        let mut full = c.clone();
+        a.as_slice().gemm(Trans::NoTrans, Trans::NoTrans, n, n, k, 2.0, n, &b, k, -1.0, &mut full, n);
+        for uplo in [Uplo::Upper, Uplo::Lower] {
+            let mut tri = c.clone();
+            a.as_slice().gemmt(uplo, Trans::NoTrans, Trans::NoTrans, n, k, 2.0, n, &b, k, -1.0, &mut tri, n);
+            for j in 0..n {
+                for i in 0..n {
+                    let stored = if uplo == Uplo::Upper { i <= j } else { i >= j };
+                    assert_eq!(tri[i + j * n], if stored { full[i + j * n] } else { c[i + j * n] });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use num_complex::Complex;
+use crate::level3::symm::symm;
+use crate::types::{Side, Uplo};
+
+pub trait Hemm {
+    type Output;
+    fn hemm(&self, side: Side, uplo: Uplo, m: usize, n: usize, alpha: Self::Output, lda: usize, b: &Self, ldb: usize, beta: Self::Output, c: &mut Self, ldc: usize);
+}
+
+impl Hemm for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn hemm(&self, side: Side, uplo: Uplo, m: usize, n: usize, alpha: Complex<f32>, lda: usize, b: &Self, ldb: usize, beta: Complex<f32>, c: &mut Self, ldc: usize) {
+        symm(self, side, uplo, m, n, alpha, lda, b, ldb, beta, c, ldc, true)
+    }
+}
+impl Hemm for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn hemm(&self, side: Side, uplo: Uplo, m: usize, n: usize, alpha: Complex<f64>, lda: usize, b: &Self, ldb: usize, beta: Complex<f64>, c: &mut Self, ldc: usize) {
+        symm(self, side, uplo, m, n, alpha, lda, b, ldb, beta, c, ldc, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_hemm() {
+        // A = [[2, 1-i], [1+i, 3]]; the diagonal imaginary parts are ignored.
+        let upper_complex_f32: Vec<Complex<f32>> = vec![Complex::new(2.0, 7.0), Complex::new(-9.0, -9.0), Complex::new(1.0, -1.0), Complex::new(3.0, 7.0)];
+        let b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0); 2];
+        let lower_complex_f64: Vec<Complex<f64>> = vec![Complex::new(2.0, 0.0), Complex::new(1.0, 1.0), Complex::new(-9.0, -9.0), Complex::new(3.0, 0.0)];
+        let b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 2];
+
+        // A * b for a single column b = [1, i]
+        upper_complex_f32.as_slice().hemm(Side::Left, Uplo::Upper, 2, 1, Complex::new(1.0, 0.0), 2, &b_complex_f32, 2, Complex::new(1.0, 0.0), &mut c_complex_f32, 2);
+        assert_eq!(c_complex_f32, vec![Complex::new(4.0, 1.0), Complex::new(2.0, 4.0)]);
+
+        // b^T * A for a single row b = [1, i]
+        lower_complex_f64.as_slice().hemm(Side::Right, Uplo::Lower, 1, 2, Complex::new(1.0, 0.0), 2, &b_complex_f64, 1, Complex::new(0.0, 0.0), &mut c_complex_f64, 1);
+        assert_eq!(c_complex_f64, vec![Complex::new(1.0, 1.0), Complex::new(1.0, 2.0)]);
+    }
+}
@@ -0,0 +1,48 @@
+use num_complex::Complex;
+use crate::level3::herk::real_diagonal;
+use crate::level3::syr2k::syr2k;
+use crate::types::{Trans, Uplo};
+
+pub trait Her2k {
+    type Output;
+    fn her2k(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: Complex<Self::Output>, lda: usize, b: &Self, ldb: usize, beta: Self::Output, c: &mut Self, ldc: usize);
+}
+
+impl Her2k for [Complex<f32>] {
+    type Output = f32;
+    fn her2k(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: Complex<f32>, lda: usize, b: &Self, ldb: usize, beta: f32, c: &mut Self, ldc: usize) {
+        syr2k(self, uplo, trans, n, k, alpha, lda, b, ldb, Complex::new(beta, 0.0), c, ldc, true);
+        real_diagonal(c, n, ldc);
+    }
+}
+impl Her2k for [Complex<f64>] {
+    type Output = f64;
+    fn her2k(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: Complex<f64>, lda: usize, b: &Self, ldb: usize, beta: f64, c: &mut Self, ldc: usize) {
+        syr2k(self, uplo, trans, n, k, alpha, lda, b, ldb, Complex::new(beta, 0.0), c, ldc, true);
+        real_diagonal(c, n, ldc);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_her2k() {
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut c_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 4];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 3.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+
+        // a b^H + b a^H = [[0, 3], [3, 0]] for the columns a = [1, i], b = [i, 2]
+        a_complex_f32.as_slice().her2k(Uplo::Upper, Trans::NoTrans, 2, 1, Complex::new(1.0, 0.0), 2, &b_complex_f32, 2, 0.0, &mut c_complex_f32, 2);
+        assert_eq!(c_complex_f32, vec![Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(3.0, 0.0), Complex::new(0.0, 0.0)]);
+
+        // i a^H b - i b^H a = [[-2, i], [-i, 4]] for the rows a = [1, i], b = [i, 2], plus C
+        a_complex_f64.as_slice().her2k(Uplo::Lower, Trans::ConjTrans, 2, 1, Complex::new(0.0, 1.0), 1, &b_complex_f64, 1, 1.0, &mut c_complex_f64, 2);
+        assert_eq!(c_complex_f64, vec![Complex::new(-1.0, 0.0), Complex::new(0.0, -1.0), Complex::new(0.0, 0.0), Complex::new(5.0, 0.0)]);
+    }
+}
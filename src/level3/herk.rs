@@ -0,0 +1,54 @@
+use num_complex::Complex;
+use crate::level3::syrk::syrk;
+use crate::scalar::Scalar;
+use crate::types::{Trans, Uplo};
+
+pub trait Herk {
+    type Output;
+    fn herk(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: Self::Output, lda: usize, beta: Self::Output, c: &mut [Complex<Self::Output>], ldc: usize);
+}
+
+impl Herk for [Complex<f32>] {
+    type Output = f32;
+    fn herk(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: f32, lda: usize, beta: f32, c: &mut [Complex<f32>], ldc: usize) {
+        syrk(self, uplo, trans, n, k, Complex::new(alpha, 0.0), lda, Complex::new(beta, 0.0), c, ldc, true);
+        real_diagonal(c, n, ldc);
+    }
+}
+impl Herk for [Complex<f64>] {
+    type Output = f64;
+    fn herk(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: f64, lda: usize, beta: f64, c: &mut [Complex<f64>], ldc: usize) {
+        syrk(self, uplo, trans, n, k, Complex::new(alpha, 0.0), lda, Complex::new(beta, 0.0), c, ldc, true);
+        real_diagonal(c, n, ldc);
+    }
+}
+
+// The Hermitian updates define the diagonal of C as real, so any imaginary part is dropped.
+pub(crate) fn real_diagonal<T: Scalar>(c: &mut [T], n: usize, ldc: usize) {
+    for j in 0..n {
+        c[j + j * ldc] = T::from_real(c[j + j * ldc].re());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_herk() {
+        // a a^H for the column a = [1+i, i] is [[2, 1-i], [1+i, 1]]
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 4];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 1.0)];
+        // Stray imaginary parts on the diagonal are dropped
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 5.0), Complex::new(1.0, 1.0), Complex::new(9.0, 9.0), Complex::new(1.0, -5.0)];
+
+        a_complex_f32.as_slice().herk(Uplo::Upper, Trans::NoTrans, 2, 1, 1.0, 2, 0.0, &mut c_complex_f32, 2);
+        assert_eq!(c_complex_f32, vec![Complex::new(2.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, -1.0), Complex::new(1.0, 0.0)]);
+
+        // a^H a for the row a = [1+i, i], taking half of it and adding C
+        a_complex_f64.as_slice().herk(Uplo::Lower, Trans::ConjTrans, 2, 1, 0.5, 1, 1.0, &mut c_complex_f64, 2);
+        assert_eq!(c_complex_f64, vec![Complex::new(2.0, 0.0), Complex::new(1.5, 0.5), Complex::new(9.0, 9.0), Complex::new(1.5, 0.0)]);
+    }
+}
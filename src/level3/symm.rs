@@ -0,0 +1,56 @@
+use crate::level2::symv::sym_at;
+use crate::level3::gemm::gemm_blocked;
+use crate::scalar::Scalar;
+use crate::types::{Side, Uplo};
+
+pub trait Symm {
+    type Output;
+    fn symm(&self, side: Side, uplo: Uplo, m: usize, n: usize, alpha: Self::Output, lda: usize, b: &Self, ldb: usize, beta: Self::Output, c: &mut Self, ldc: usize);
+}
+
+impl Symm for [f32] {
+    type Output = f32;
+    fn symm(&self, side: Side, uplo: Uplo, m: usize, n: usize, alpha: f32, lda: usize, b: &Self, ldb: usize, beta: f32, c: &mut Self, ldc: usize) {
+        symm(self, side, uplo, m, n, alpha, lda, b, ldb, beta, c, ldc, false)
+    }
+}
+impl Symm for [f64] {
+    type Output = f64;
+    fn symm(&self, side: Side, uplo: Uplo, m: usize, n: usize, alpha: f64, lda: usize, b: &Self, ldb: usize, beta: f64, c: &mut Self, ldc: usize) {
+        symm(self, side, uplo, m, n, alpha, lda, b, ldb, beta, c, ldc, false)
+    }
+}
+
+// C = alpha * A * B + beta * C (Left, A is m x m) or C = alpha * B * A + beta * C (Right,
+// A is n x n), with A symmetric (or Hermitian, when `conj`) and only its `uplo` triangle
+// read. The full A is presented to the GEMM driver through an accessor, so only packing
+// differs from a general product.
+pub(crate) fn symm<T: Scalar>(a: &[T], side: Side, uplo: Uplo, m: usize, n: usize, alpha: T, lda: usize, b: &[T], ldb: usize, beta: T, c: &mut [T], ldc: usize, conj: bool) {
+    match side {
+        Side::Left => gemm_blocked(m, n, m, alpha, |i, p| sym_at(a, lda, uplo, conj, i, p), |p, j| b[p + j * ldb], beta, c, ldc),
+        Side::Right => gemm_blocked(m, n, n, alpha, |i, p| b[i + p * ldb], |p, j| sym_at(a, lda, uplo, conj, p, j), beta, c, ldc),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symm() {
+        // A = [[1, 2], [2, 3]] with garbage in the unused triangle, B = [[1, 0, 1], [0, 1, 1]]
+        let a_f32: Vec<f32> = vec![1.0, 2.0, -9.0, 3.0];
+        let b_f32: Vec<f32> = vec![1.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let mut c_f32: Vec<f32> = vec![1.0; 6];
+        // A = [[1, 2, 0], [2, 3, 1], [0, 1, 2]] upper, B is 2 x 3
+        let a_f64: Vec<f64> = vec![1.0, -9.0, -9.0, 2.0, 3.0, -9.0, 0.0, 1.0, 2.0];
+        let b_f64: Vec<f64> = vec![1.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let mut c_f64: Vec<f64> = vec![f64::NAN; 6];
+
+        a_f32.as_slice().symm(Side::Left, Uplo::Lower, 2, 3, 1.0, 2, &b_f32, 2, 1.0, &mut c_f32, 2);
+        assert_eq!(c_f32, vec![2.0, 3.0, 3.0, 4.0, 4.0, 6.0]);
+
+        a_f64.as_slice().symm(Side::Right, Uplo::Upper, 2, 3, 2.0, 3, &b_f64, 2, 0.0, &mut c_f64, 2);
+        assert_eq!(c_f64, vec![2.0, 4.0, 6.0, 8.0, 4.0, 6.0]);
+    }
+}
@@ -0,0 +1,83 @@
+use num_complex::Complex;
+use crate::level3::gemm::gemm_blocked_triangle;
+use crate::level3::syrk::rank_k_accessors;
+use crate::scalar::Scalar;
+use crate::types::{Trans, Uplo};
+
+pub trait Syr2k {
+    type Output;
+    fn syr2k(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: Self::Output, lda: usize, b: &Self, ldb: usize, beta: Self::Output, c: &mut Self, ldc: usize);
+}
+
+impl Syr2k for [f32] {
+    type Output = f32;
+    fn syr2k(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: f32, lda: usize, b: &Self, ldb: usize, beta: f32, c: &mut Self, ldc: usize) {
+        syr2k(self, uplo, trans, n, k, alpha, lda, b, ldb, beta, c, ldc, false)
+    }
+}
+impl Syr2k for [f64] {
+    type Output = f64;
+    fn syr2k(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: f64, lda: usize, b: &Self, ldb: usize, beta: f64, c: &mut Self, ldc: usize) {
+        syr2k(self, uplo, trans, n, k, alpha, lda, b, ldb, beta, c, ldc, false)
+    }
+}
+impl Syr2k for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn syr2k(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: Complex<f32>, lda: usize, b: &Self, ldb: usize, beta: Complex<f32>, c: &mut Self, ldc: usize) {
+        syr2k(self, uplo, trans, n, k, alpha, lda, b, ldb, beta, c, ldc, false)
+    }
+}
+impl Syr2k for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn syr2k(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: Complex<f64>, lda: usize, b: &Self, ldb: usize, beta: Complex<f64>, c: &mut Self, ldc: usize) {
+        syr2k(self, uplo, trans, n, k, alpha, lda, b, ldb, beta, c, ldc, false)
+    }
+}
+
+// C = alpha * op(A) * op(B)^T + alpha * op(B) * op(A)^T + beta * C, or with `conj`
+// C = alpha * op(A) * op(B)^H + conj(alpha) * op(B) * op(A)^H + beta * C,
+// over the `uplo` triangle of the n x n C, as two triangle-restricted products.
+pub(crate) fn syr2k<T: Scalar>(a: &[T], uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: T, lda: usize, b: &[T], ldb: usize, beta: T, c: &mut [T], ldc: usize, conj: bool) {
+    let (a_left, a_right) = rank_k_accessors(a, trans, lda, conj);
+    let (b_left, b_right) = rank_k_accessors(b, trans, ldb, conj);
+    let alpha2 = if conj { alpha.conj() } else { alpha };
+    gemm_blocked_triangle(uplo, n, k, alpha, a_left, b_right, beta, c, ldc);
+    gemm_blocked_triangle(uplo, n, k, alpha2, b_left, a_right, T::one(), c, ldc);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_syr2k() {
+        // a = [1, 2], b = [3, 4] as columns: a b^T + b a^T = [[6, 10], [10, 16]]
+        let a_f32: Vec<f32> = vec![1.0, 2.0];
+        let b_f32: Vec<f32> = vec![3.0, 4.0];
+        let mut c_f32: Vec<f32> = vec![0.0; 4];
+        // The same vectors as rows, with Trans
+        let a_f64: Vec<f64> = vec![1.0, 2.0];
+        let b_f64: Vec<f64> = vec![3.0, 4.0];
+        let mut c_f64: Vec<f64> = vec![1.0; 4];
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut c_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 4];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 4];
+
+        a_f32.as_slice().syr2k(Uplo::Upper, Trans::NoTrans, 2, 1, 1.0, 2, &b_f32, 2, 0.0, &mut c_f32, 2);
+        assert_eq!(c_f32, vec![6.0, 0.0, 10.0, 16.0]);
+
+        a_f64.as_slice().syr2k(Uplo::Lower, Trans::Trans, 2, 1, 0.5, 1, &b_f64, 1, 1.0, &mut c_f64, 2);
+        assert_eq!(c_f64, vec![4.0, 6.0, 1.0, 9.0]);
+
+        // a b^T + b a^T = [[2i, 1], [1, 4i]] for a = [1, i], b = [i, 2]
+        a_complex_f32.as_slice().syr2k(Uplo::Upper, Trans::NoTrans, 2, 1, Complex::new(1.0, 0.0), 2, &b_complex_f32, 2, Complex::new(0.0, 0.0), &mut c_complex_f32, 2);
+        assert_eq!(c_complex_f32, vec![Complex::new(0.0, 2.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(0.0, 4.0)]);
+
+        a_complex_f64.as_slice().syr2k(Uplo::Lower, Trans::Trans, 2, 1, Complex::new(0.0, 1.0), 1, &b_complex_f64, 1, Complex::new(0.0, 0.0), &mut c_complex_f64, 2);
+        assert_eq!(c_complex_f64, vec![Complex::new(-2.0, 0.0), Complex::new(0.0, 1.0), Complex::new(0.0, 0.0), Complex::new(-4.0, 0.0)]);
+    }
+}
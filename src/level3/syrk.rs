@@ -0,0 +1,105 @@
+use num_complex::Complex;
+use crate::level3::gemm::gemm_blocked_triangle;
+use crate::scalar::Scalar;
+use crate::types::{Trans, Uplo};
+
+pub trait Syrk {
+    type Output;
+    fn syrk(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: Self::Output, lda: usize, beta: Self::Output, c: &mut Self, ldc: usize);
+}
+
+impl Syrk for [f32] {
+    type Output = f32;
+    fn syrk(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: f32, lda: usize, beta: f32, c: &mut Self, ldc: usize) {
+        syrk(self, uplo, trans, n, k, alpha, lda, beta, c, ldc, false)
+    }
+}
+impl Syrk for [f64] {
+    type Output = f64;
+    fn syrk(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: f64, lda: usize, beta: f64, c: &mut Self, ldc: usize) {
+        syrk(self, uplo, trans, n, k, alpha, lda, beta, c, ldc, false)
+    }
+}
+impl Syrk for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn syrk(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: Complex<f32>, lda: usize, beta: Complex<f32>, c: &mut Self, ldc: usize) {
+        syrk(self, uplo, trans, n, k, alpha, lda, beta, c, ldc, false)
+    }
+}
+impl Syrk for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn syrk(&self, uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: Complex<f64>, lda: usize, beta: Complex<f64>, c: &mut Self, ldc: usize) {
+        syrk(self, uplo, trans, n, k, alpha, lda, beta, c, ldc, false)
+    }
+}
+
+// Element (i, p) of op(A) and element (p, j) of op(A)^T (or op(A)^H, when `conj`), where
+// op(A) is n x k: A itself for NoTrans, otherwise A^T (or A^H).
+pub(crate) fn rank_k_accessors<T: Scalar>(a: &[T], trans: Trans, lda: usize, conj: bool) -> (impl Fn(usize, usize) -> T + Sync, impl Fn(usize, usize) -> T + Sync) {
+    let c = move |v: T| if conj { v.conj() } else { v };
+    let transposed = trans != Trans::NoTrans;
+    let left = move |i: usize, p: usize| if transposed { c(a[p + i * lda]) } else { a[i + p * lda] };
+    let right = move |p: usize, j: usize| if transposed { a[p + j * lda] } else { c(a[j + p * lda]) };
+    (left, right)
+}
+
+// C = alpha * op(A) * op(A)^T + beta * C (op(A)^H when `conj`) over the `uplo` triangle of
+// the n x n C.
+pub(crate) fn syrk<T: Scalar>(a: &[T], uplo: Uplo, trans: Trans, n: usize, k: usize, alpha: T, lda: usize, beta: T, c: &mut [T], ldc: usize, conj: bool) {
+    let (left, right) = rank_k_accessors(a, trans, lda, conj);
+    gemm_blocked_triangle(uplo, n, k, alpha, left, right, beta, c, ldc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_syrk() {
+        // A = [[1, 2], [3, 4], [5, 6]]; A * A^T = [[5, 11, 17], [11, 25, 39], [17, 39, 61]]
+        let a_f32: Vec<f32> = vec![1.0, 3.0, 5.0, 2.0, 4.0, 6.0];
+        let mut c_f32: Vec<f32> = vec![-1.0; 9];
+        // A^T * A = [[35, 44], [44, 56]] from the same A, stored transposed for Trans
+        let a_f64: Vec<f64> = vec![1.0, 3.0, 5.0, 2.0, 4.0, 6.0];
+        let mut c_f64: Vec<f64> = vec![1.0; 4];
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 0.0); 4];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(0.0, 1.0)];
+        let mut c_complex_f64: Vec<Complex<f64>> = vec![Complex::new(9.0, 9.0); 4];
+
+        // The strictly upper triangle is left alone
+        a_f32.as_slice().syrk(Uplo::Lower, Trans::NoTrans, 3, 2, 1.0, 3, 0.0, &mut c_f32, 3);
+        assert_eq!(c_f32, vec![5.0, 11.0, 17.0, -1.0, 25.0, 39.0, -1.0, -1.0, 61.0]);
+
+        a_f64.as_slice().syrk(Uplo::Upper, Trans::Trans, 2, 3, 1.0, 3, 1.0, &mut c_f64, 2);
+        assert_eq!(c_f64, vec![36.0, 1.0, 45.0, 57.0]);
+
+        // a a^T for the column a = [1+i, i], without conjugation
+        a_complex_f32.as_slice().syrk(Uplo::Upper, Trans::NoTrans, 2, 1, Complex::new(1.0, 0.0), 2, Complex::new(0.0, 0.0), &mut c_complex_f32, 2);
+        assert_eq!(c_complex_f32, vec![Complex::new(0.0, 2.0), Complex::new(0.0, 0.0), Complex::new(-1.0, 1.0), Complex::new(-1.0, 0.0)]);
+
+        // a^T a for the row a = [1+i, i]
+        a_complex_f64.as_slice().syrk(Uplo::Lower, Trans::Trans, 2, 1, Complex::new(1.0, 0.0), 1, Complex::new(0.0, 0.0), &mut c_complex_f64, 2);
+        assert_eq!(c_complex_f64, vec![Complex::new(0.0, 2.0), Complex::new(-1.0, 1.0), Complex::new(9.0, 9.0), Complex::new(-1.0, 0.0)]);
+
+        // Large enough to cross panel and tile boundaries; the other triangle is never written
+        let (n, k) = (203, 37);
+        let a: Vec<f64> = (0..n * k).map(|v| ((v * 7) % 13) as f64 - 6.0).collect();
+        for uplo in [Uplo::Upper, Uplo::Lower] {
+            let mut c = vec![f64::NAN; n * n];
+            a.as_slice().syrk(uplo, Trans::NoTrans, n, k, 1.0, n, 0.0, &mut c, n);
+            for j in 0..n {
+                for i in 0..n {
+                    let stored = if uplo == Uplo::Upper { i <= j } else { i >= j };
+                    if stored {
+                        let dot: f64 = (0..k).map(|p| a[i + p * n] * a[j + p * n]).sum();
+                        assert_eq!(c[i + j * n], dot);
+                    } else {
+                        assert!(c[i + j * n].is_nan());
+                    }
+                }
+            }
+        }
+    }
+}
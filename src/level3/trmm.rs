@@ -0,0 +1,192 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::extensions::omatcopy::omatcopy;
+use crate::level2::trmv::op_is_lower;
+use crate::level3::gemm::{gemm_blocked, scale};
+use crate::scalar::Scalar;
+use crate::types::{op_at, Diag, Side, Trans, Uplo};
+
+// Diagonal blocks at most this large are handled directly; larger ones are split in two
+// and the off-diagonal block is applied with GEMM.
+pub(crate) const NB: usize = 64;
+
+pub trait Trmm {
+    type Output;
+    fn trmm(&self, side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: Self::Output, lda: usize, b: &mut Self, ldb: usize);
+}
+
+impl Trmm for [f32] {
+    type Output = f32;
+    fn trmm(&self, side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: f32, lda: usize, b: &mut Self, ldb: usize) {
+        trmm(self, side, uplo, transa, diag, m, n, alpha, lda, b, ldb)
+    }
+}
+impl Trmm for [f64] {
+    type Output = f64;
+    fn trmm(&self, side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: f64, lda: usize, b: &mut Self, ldb: usize) {
+        trmm(self, side, uplo, transa, diag, m, n, alpha, lda, b, ldb)
+    }
+}
+impl Trmm for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn trmm(&self, side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: Complex<f32>, lda: usize, b: &mut Self, ldb: usize) {
+        trmm(self, side, uplo, transa, diag, m, n, alpha, lda, b, ldb)
+    }
+}
+impl Trmm for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn trmm(&self, side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: Complex<f64>, lda: usize, b: &mut Self, ldb: usize) {
+        trmm(self, side, uplo, transa, diag, m, n, alpha, lda, b, ldb)
+    }
+}
+
+// B = alpha * op(A) * B (Left, A is m x m) or B = alpha * B * op(A) (Right, A is n x n).
+fn trmm<T: Scalar>(a: &[T], side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: T, lda: usize, b: &mut [T], ldb: usize) {
+    if m == 0 || n == 0 {
+        return;
+    }
+    scale(b, m, n, ldb, alpha);
+    if alpha == T::zero() {
+        return;
+    }
+    let lower = op_is_lower(uplo, transa);
+    let unit = diag == Diag::Unit;
+    match side {
+        Side::Left => tri_mm(0, m, n, lower, unit, &|i, j| op_at(a, transa, lda, i, j), b, ldb),
+        Side::Right => transposed(m, n, b, ldb, |bt, ldbt| tri_mm(0, n, m, !lower, unit, &|i, j| op_at(a, transa, lda, j, i), bt, ldbt)),
+    }
+}
+
+// Runs `f` on B^T, so a Right-side operation B * op(A) = (op(A)^T * B^T)^T can be done on
+// the left: B is transposed into a workspace, handed to `f` with its leading dimension, and
+// transposed back.
+pub(crate) fn transposed<T: Scalar, F: FnOnce(&mut [T], usize)>(m: usize, n: usize, b: &mut [T], ldb: usize, f: F) {
+    let mut bt = vec![T::zero(); n * m];
+    omatcopy(b, Trans::Trans, m, n, T::one(), ldb, &mut bt, n);
+    f(&mut bt, n);
+    omatcopy(&bt, Trans::Trans, n, m, T::one(), n, b, ldb);
+}
+
+// Rows r0..r1 of the n columns of B, packed with leading dimension r1 - r0. Blocks that feed
+// a GEMM update of other rows of the same B are copied out so B can be borrowed mutably.
+pub(crate) fn copy_rows<T: Scalar>(b: &[T], ldb: usize, r0: usize, r1: usize, n: usize) -> Vec<T> {
+    b
+    .chunks(ldb)
+    .take(n)
+    .flat_map(|col| col[r0..r1].iter().copied())
+    .collect()
+}
+
+// B = L * B over rows r0..r1 of the n columns of B, where L is the diagonal block r0..r1 of
+// a triangular matrix whose element (i, j) is `at(i, j)`. The block is split in two: the half
+// that depends on the other is finished first with one GEMM on a copy of the other half's
+// old rows, then each half recurses. Blocks of order NB are multiplied column by column,
+// with the columns in parallel.
+fn tri_mm<T: Scalar, F: Fn(usize, usize) -> T + Sync>(r0: usize, r1: usize, n: usize, lower: bool, unit: bool, at: &F, b: &mut [T], ldb: usize) {
+    if r1 - r0 <= NB {
+        b
+        .par_chunks_mut(ldb)
+        .take(n)
+        .for_each(|col| {
+            let x = &mut col[r0..r1];
+            let s = x.len();
+            let d = |i: usize, v: T| if unit { v } else { at(r0 + i, r0 + i) * v };
+            if lower {
+                for i in (0..s).rev() {
+                    let mut t = d(i, x[i]);
+                    for (p, &xp) in x.iter().enumerate().take(i) {
+                        t += at(r0 + i, r0 + p) * xp;
+                    }
+                    x[i] = t;
+                }
+            } else {
+                for i in 0..s {
+                    let mut t = d(i, x[i]);
+                    for (p, &xp) in x.iter().enumerate().skip(i + 1) {
+                        t += at(r0 + i, r0 + p) * xp;
+                    }
+                    x[i] = t;
+                }
+            }
+        });
+        return;
+    }
+    let mid = r0 + (r1 - r0) / 2;
+    if lower {
+        tri_mm(mid, r1, n, lower, unit, at, b, ldb);
+        let top = copy_rows(b, ldb, r0, mid, n);
+        let kt = mid - r0;
+        gemm_blocked(r1 - mid, n, kt, T::one(), |i, p| at(mid + i, r0 + p), |p, j| top[p + j * kt], T::one(), &mut b[mid..], ldb);
+        tri_mm(r0, mid, n, lower, unit, at, b, ldb);
+    } else {
+        tri_mm(r0, mid, n, lower, unit, at, b, ldb);
+        let bottom = copy_rows(b, ldb, mid, r1, n);
+        let kb = r1 - mid;
+        gemm_blocked(mid - r0, n, kb, T::one(), |i, p| at(r0 + i, mid + p), |p, j| bottom[p + j * kb], T::one(), &mut b[r0..], ldb);
+        tri_mm(mid, r1, n, lower, unit, at, b, ldb);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_trmm() {
+        // A = [[1, 0], [2, 3]] with garbage above the diagonal, B = [[1, 2], [3, 4]]
+        let a_f32: Vec<f32> = vec![1.0, 2.0, -9.0, 3.0];
+        let mut b_f32: Vec<f32> = vec![1.0, 3.0, 2.0, 4.0];
+        // Upper A with an ignored diagonal; op(A) = A^T = [[1, 0], [4, 1]]
+        let a_f64: Vec<f64> = vec![5.0, -9.0, 4.0, 8.0];
+        let mut b_f64: Vec<f64> = vec![1.0, 3.0, 2.0, 4.0];
+        // A = [[i, 1], [*, 2]], op(A) = A^H = [[-i, 0], [1, 2]]
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(-9.0, 0.0), Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)];
+        let mut b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        // A = [[1, *], [i, 2]], B = [1, 1] as a row
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(-9.0, 0.0), Complex::new(2.0, 0.0)];
+        let mut b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)];
+
+        a_f32.as_slice().trmm(Side::Left, Uplo::Lower, Trans::NoTrans, Diag::NonUnit, 2, 2, 1.0, 2, &mut b_f32, 2);
+        assert_eq!(b_f32, vec![1.0, 11.0, 2.0, 16.0]);
+
+        a_f64.as_slice().trmm(Side::Right, Uplo::Upper, Trans::Trans, Diag::Unit, 2, 2, 2.0, 2, &mut b_f64, 2);
+        assert_eq!(b_f64, vec![18.0, 38.0, 4.0, 8.0]);
+
+        a_complex_f32.as_slice().trmm(Side::Left, Uplo::Upper, Trans::ConjTrans, Diag::NonUnit, 2, 1, Complex::new(1.0, 0.0), 2, &mut b_complex_f32, 2);
+        assert_eq!(b_complex_f32, vec![Complex::new(0.0, -1.0), Complex::new(1.0, 2.0)]);
+
+        a_complex_f64.as_slice().trmm(Side::Right, Uplo::Lower, Trans::NoTrans, Diag::NonUnit, 1, 2, Complex::new(1.0, 0.0), 2, &mut b_complex_f64, 1);
+        assert_eq!(b_complex_f64, vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)]);
+
+        // Every option combination past the recursion cutoff, against a product with the
+        // triangle written out densely
+        let (m, n) = (150, 90);
+        let b: Vec<f64> = (0..m * n).map(|v| ((v * 5) % 17) as f64 - 8.0).collect();
+        for side in [Side::Left, Side::Right] {
+            let k = if side == Side::Left { m } else { n };
+            let a: Vec<f64> = (0..k * k).map(|v| ((v * 3) % 13) as f64 - 6.0).collect();
+            for uplo in [Uplo::Upper, Uplo::Lower] {
+                for transa in [Trans::NoTrans, Trans::Trans] {
+                    for diag in [Diag::Unit, Diag::NonUnit] {
+                        let mut t = vec![0.0; k * k];
+                        for j in 0..k {
+                            for i in 0..k {
+                                let stored = if uplo == Uplo::Upper { i < j } else { i > j };
+                                t[i + j * k] = if i == j && diag == Diag::Unit { 1.0 } else if stored || i == j { a[i + j * k] } else { 0.0 };
+                            }
+                        }
+                        let mut expected = vec![0.0; m * n];
+                        let mut got = b.clone();
+                        match side {
+                            Side::Left => gemm_blocked(m, n, m, 2.0, |i, p| op_at(&t, transa, k, i, p), |p, j| b[p + j * m], 0.0, &mut expected, m),
+                            Side::Right => gemm_blocked(m, n, n, 2.0, |i, p| b[i + p * m], |p, j| op_at(&t, transa, k, p, j), 0.0, &mut expected, m),
+                        }
+                        a.as_slice().trmm(side, uplo, transa, diag, m, n, 2.0, k, &mut got, m);
+                        assert_eq!(got, expected);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use num_complex::Complex;
+use rayon::prelude::*;
+use crate::level2::trmv::op_is_lower;
+use crate::level3::gemm::{gemm_blocked, scale};
+use crate::level3::trmm::{copy_rows, transposed, NB};
+use crate::scalar::Scalar;
+use crate::types::{op_at, Diag, Side, Trans, Uplo};
+
+pub trait Trsm {
+    type Output;
+    fn trsm(&self, side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: Self::Output, lda: usize, b: &mut Self, ldb: usize);
+}
+
+impl Trsm for [f32] {
+    type Output = f32;
+    fn trsm(&self, side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: f32, lda: usize, b: &mut Self, ldb: usize) {
+        trsm(self, side, uplo, transa, diag, m, n, alpha, lda, b, ldb)
+    }
+}
+impl Trsm for [f64] {
+    type Output = f64;
+    fn trsm(&self, side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: f64, lda: usize, b: &mut Self, ldb: usize) {
+        trsm(self, side, uplo, transa, diag, m, n, alpha, lda, b, ldb)
+    }
+}
+impl Trsm for [Complex<f32>] {
+    type Output = Complex<f32>;
+    fn trsm(&self, side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: Complex<f32>, lda: usize, b: &mut Self, ldb: usize) {
+        trsm(self, side, uplo, transa, diag, m, n, alpha, lda, b, ldb)
+    }
+}
+impl Trsm for [Complex<f64>] {
+    type Output = Complex<f64>;
+    fn trsm(&self, side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: Complex<f64>, lda: usize, b: &mut Self, ldb: usize) {
+        trsm(self, side, uplo, transa, diag, m, n, alpha, lda, b, ldb)
+    }
+}
+
+// Solves op(A) * X = alpha * B (Left, A is m x m) or X * op(A) = alpha * B (Right, A is
+// n x n), overwriting B with X.
+fn trsm<T: Scalar>(a: &[T], side: Side, uplo: Uplo, transa: Trans, diag: Diag, m: usize, n: usize, alpha: T, lda: usize, b: &mut [T], ldb: usize) {
+    if m == 0 || n == 0 {
+        return;
+    }
+    scale(b, m, n, ldb, alpha);
+    if alpha == T::zero() {
+        return;
+    }
+    let lower = op_is_lower(uplo, transa);
+    let unit = diag == Diag::Unit;
+    match side {
+        Side::Left => tri_sm(0, m, n, lower, unit, &|i, j| op_at(a, transa, lda, i, j), b, ldb),
+        Side::Right => transposed(m, n, b, ldb, |bt, ldbt| tri_sm(0, n, m, !lower, unit, &|i, j| op_at(a, transa, lda, j, i), bt, ldbt)),
+    }
+}
+
+// Solves L * X = B in place over rows r0..r1 of the n columns of B, where L is the diagonal
+// block r0..r1 of a triangular matrix whose element (i, j) is `at(i, j)`. The half solved
+// first is eliminated from the other with one GEMM, so almost all of the work runs at GEMM
+// speed; blocks of order NB are solved by substitution, with the columns in parallel.
+fn tri_sm<T: Scalar, F: Fn(usize, usize) -> T + Sync>(r0: usize, r1: usize, n: usize, lower: bool, unit: bool, at: &F, b: &mut [T], ldb: usize) {
+    if r1 - r0 <= NB {
+        b
+        .par_chunks_mut(ldb)
+        .take(n)
+        .for_each(|col| {
+            let x = &mut col[r0..r1];
+            let s = x.len();
+            if lower {
+                for i in 0..s {
+                    let mut t = x[i];
+                    for (p, &xp) in x.iter().enumerate().take(i) {
+                        t -= at(r0 + i, r0 + p) * xp;
+                    }
+                    x[i] = if unit { t } else { t / at(r0 + i, r0 + i) };
+                }
+            } else {
+                for i in (0..s).rev() {
+                    let mut t = x[i];
+                    for (p, &xp) in x.iter().enumerate().skip(i + 1) {
+                        t -= at(r0 + i, r0 + p) * xp;
+                    }
+                    x[i] = if unit { t } else { t / at(r0 + i, r0 + i) };
+                }
+            }
+        });
+        return;
+    }
+    let mid = r0 + (r1 - r0) / 2;
+    if lower {
+        tri_sm(r0, mid, n, lower, unit, at, b, ldb);
+        let top = copy_rows(b, ldb, r0, mid, n);
+        let kt = mid - r0;
+        gemm_blocked(r1 - mid, n, kt, -T::one(), |i, p| at(mid + i, r0 + p), |p, j| top[p + j * kt], T::one(), &mut b[mid..], ldb);
+        tri_sm(mid, r1, n, lower, unit, at, b, ldb);
+    } else {
+        tri_sm(mid, r1, n, lower, unit, at, b, ldb);
+        let bottom = copy_rows(b, ldb, mid, r1, n);
+        let kb = r1 - mid;
+        gemm_blocked(mid - r0, n, kb, -T::one(), |i, p| at(r0 + i, mid + p), |p, j| bottom[p + j * kb], T::one(), &mut b[r0..], ldb);
+        tri_sm(r0, mid, n, lower, unit, at, b, ldb);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level3::trmm::Trmm;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_trsm() {
+        // The systems multiplied out in the trmm test, solved back
+        let a_f32: Vec<f32> = vec![1.0, 2.0, -9.0, 3.0];
+        let mut b_f32: Vec<f32> = vec![1.0, 11.0, 2.0, 16.0];
+        let a_f64: Vec<f64> = vec![5.0, -9.0, 4.0, 8.0];
+        let mut b_f64: Vec<f64> = vec![18.0, 38.0, 4.0, 8.0];
+        let a_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(-9.0, 0.0), Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)];
+        let mut b_complex_f32: Vec<Complex<f32>> = vec![Complex::new(0.0, -1.0), Complex::new(1.0, 2.0)];
+        let a_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0), Complex::new(-9.0, 0.0), Complex::new(2.0, 0.0)];
+        let mut b_complex_f64: Vec<Complex<f64>> = vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)];
+
+        a_f32.as_slice().trsm(Side::Left, Uplo::Lower, Trans::NoTrans, Diag::NonUnit, 2, 2, 1.0, 2, &mut b_f32, 2);
+        assert_eq!(b_f32, vec![1.0, 3.0, 2.0, 4.0]);
+
+        a_f64.as_slice().trsm(Side::Right, Uplo::Upper, Trans::Trans, Diag::Unit, 2, 2, 0.5, 2, &mut b_f64, 2);
+        assert_eq!(b_f64, vec![1.0, 3.0, 2.0, 4.0]);
+
+        a_complex_f32.as_slice().trsm(Side::Left, Uplo::Upper, Trans::ConjTrans, Diag::NonUnit, 2, 1, Complex::new(1.0, 0.0), 2, &mut b_complex_f32, 2);
+        assert_eq!(b_complex_f32, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+
+        a_complex_f64.as_slice().trsm(Side::Right, Uplo::Lower, Trans::NoTrans, Diag::NonUnit, 1, 2, Complex::new(1.0, 0.0), 2, &mut b_complex_f64, 1);
+        assert_eq!(b_complex_f64, vec![Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)]);
+
+        // Every option combination past the recursion cutoff: multiplying the solution back
+        // recovers alpha * B
+        let (m, n) = (150, 90);
+        let b: Vec<f64> = (0..m * n).map(|v| ((v * 5) % 17) as f64 - 8.0).collect();
+        for side in [Side::Left, Side::Right] {
+            let k = if side == Side::Left { m } else { n };
+            let a: Vec<f64> = (0..k * k).map(|v| if v % (k + 1) == 0 { 4.0 } else { (((v * 3) % 13) as f64 - 6.0) / (8.0 * k as f64) }).collect();
+            for uplo in [Uplo::Upper, Uplo::Lower] {
+                for transa in [Trans::NoTrans, Trans::Trans] {
+                    for diag in [Diag::Unit, Diag::NonUnit] {
+                        let mut x = b.clone();
+                        a.as_slice().trsm(side, uplo, transa, diag, m, n, 2.0, k, &mut x, m);
+                        a.as_slice().trmm(side, uplo, transa, diag, m, n, 1.0, k, &mut x, m);
+                        for (x, b) in x.iter().zip(&b) {
+                            assert!((x - 2.0 * b).abs() < 1e-10);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -10,4 +10,15 @@ Tasks:
 3. Implement BLAS level 3 operations
 4. Implement performance testing against netlib
 */
+// BLAS routine signatures are long by specification.
+#![allow(clippy::too_many_arguments)]
+
 pub mod level1;
+pub mod level2;
+pub mod level3;
+pub mod extensions;
+pub mod modular;
+pub mod scalar;
+pub mod semiring;
+pub mod storage;
+pub mod types;
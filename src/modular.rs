@@ -0,0 +1,129 @@
+// Integers modulo P, for exact products over a finite field. Values are kept reduced to
+// 0..P with P at most 2^32, so the product of two values fits in a u64 and a sum of up to
+// 2^64 such products fits in a u128. The matrix kernels exploit this by accumulating raw
+// products and reducing once per output element (per KC block in GEMM) rather than once
+// per multiply-add: delayed reduction.
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Modular<const P: u64>(u64);
+
+impl<const P: u64> Modular<P> {
+    const VALID: () = assert!(P >= 2 && P <= 1 << 32, "the modulus must be in 2..=2^32");
+
+    pub fn new(value: u64) -> Self {
+        let () = Self::VALID;
+        Modular(value % P)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    pub fn zero() -> Self {
+        Modular(0)
+    }
+
+    pub fn one() -> Self {
+        Modular::new(1)
+    }
+
+    pub fn pow(self, mut e: u64) -> Self {
+        let (mut base, mut acc) = (self, Self::one());
+        while e > 0 {
+            if e & 1 == 1 {
+                acc *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        acc
+    }
+
+    // The multiplicative inverse by Fermat's little theorem, so P must be prime.
+    pub fn inv(self) -> Self {
+        self.pow(P - 2)
+    }
+
+    // Reduces an accumulated sum of raw products.
+    pub(crate) fn reduce(acc: u128) -> Self {
+        Modular((acc % P as u128) as u64)
+    }
+
+    // The unreduced product of two values, for accumulation in a u128.
+    pub(crate) fn raw_mul(self, rhs: Self) -> u128 {
+        (self.0 * rhs.0) as u128
+    }
+}
+
+impl<const P: u64> Add for Modular<P> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let s = self.0 + rhs.0;
+        Modular(if s >= P { s - P } else { s })
+    }
+}
+impl<const P: u64> Sub for Modular<P> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Modular(if self.0 >= rhs.0 { self.0 - rhs.0 } else { self.0 + P - rhs.0 })
+    }
+}
+impl<const P: u64> Mul for Modular<P> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Modular(self.0 * rhs.0 % P)
+    }
+}
+impl<const P: u64> Neg for Modular<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Modular(if self.0 == 0 { 0 } else { P - self.0 })
+    }
+}
+impl<const P: u64> AddAssign for Modular<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<const P: u64> SubAssign for Modular<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<const P: u64> MulAssign for Modular<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl<const P: u64> Sum for Modular<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::reduce(iter.map(|x| x.0 as u128).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modular() {
+        type F7 = Modular<7>;
+        type F = Modular<4294967291>;
+
+        assert_eq!(F7::new(12).value(), 5);
+        assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+        assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+        assert_eq!(-F7::new(3), F7::new(4));
+        assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+        assert_eq!(F7::new(3).inv(), F7::new(5));
+        assert_eq!((1..7).map(F7::new).sum::<F7>(), F7::zero());
+
+        // The largest prime below 2^32: products use the full u64 range
+        let x = F::new(4294967290);
+        assert_eq!(x * x, F::one());
+        assert_eq!(x + x, F::new(4294967289));
+        assert_eq!(F::new(123456789).inv() * F::new(123456789), F::one());
+    }
+}
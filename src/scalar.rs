@@ -0,0 +1,87 @@
+use num_complex::Complex;
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Real part type of a scalar, with the square root taken at the end of a norm.
+pub trait Real: Copy + Send + Sync {
+    fn sqrt(self) -> Self;
+}
+
+impl Real for f32 {
+    fn sqrt(self) -> Self { f32::sqrt(self) }
+}
+impl Real for f64 {
+    fn sqrt(self) -> Self { f64::sqrt(self) }
+}
+// Rounds down, and panics on negative values.
+impl Real for i32 {
+    fn sqrt(self) -> Self { self.isqrt() }
+}
+
+// Element type shared by the matrix kernels. `conj` is the identity for real types.
+pub trait Scalar:
+    Copy
+    + Send
+    + Sync
+    + Debug
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + Sum
+{
+    type Real: Real;
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn conj(self) -> Self;
+    fn re(self) -> Self::Real;
+    fn from_real(re: Self::Real) -> Self;
+}
+
+impl Scalar for f32 {
+    type Real = f32;
+    fn zero() -> Self { 0.0 }
+    fn one() -> Self { 1.0 }
+    fn conj(self) -> Self { self }
+    fn re(self) -> Self::Real { self }
+    fn from_real(re: f32) -> Self { re }
+}
+impl Scalar for f64 {
+    type Real = f64;
+    fn zero() -> Self { 0.0 }
+    fn one() -> Self { 1.0 }
+    fn conj(self) -> Self { self }
+    fn re(self) -> Self::Real { self }
+    fn from_real(re: f64) -> Self { re }
+}
+impl Scalar for Complex<f32> {
+    type Real = f32;
+    fn zero() -> Self { Complex::new(0.0, 0.0) }
+    fn one() -> Self { Complex::new(1.0, 0.0) }
+    fn conj(self) -> Self { Complex::conj(&self) }
+    fn re(self) -> Self::Real { self.re }
+    fn from_real(re: f32) -> Self { Complex::new(re, 0.0) }
+}
+impl Scalar for Complex<f64> {
+    type Real = f64;
+    fn zero() -> Self { Complex::new(0.0, 0.0) }
+    fn one() -> Self { Complex::new(1.0, 0.0) }
+    fn conj(self) -> Self { Complex::conj(&self) }
+    fn re(self) -> Self::Real { self.re }
+    fn from_real(re: f64) -> Self { Complex::new(re, 0.0) }
+}
+// The accumulator of the integer GEMM, which reuses the blocked driver.
+impl Scalar for i32 {
+    type Real = i32;
+    fn zero() -> Self { 0 }
+    fn one() -> Self { 1 }
+    fn conj(self) -> Self { self }
+    fn re(self) -> Self::Real { self }
+    fn from_real(re: i32) -> Self { re }
+}
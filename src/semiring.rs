@@ -0,0 +1,70 @@
+// Element types for products over semirings other than ordinary arithmetic. Each wraps a
+// plain value; `add` and `mul` are the semiring operations, `zero` is the identity of `add`
+// (and annihilates under `mul`) and `one` is the identity of `mul`.
+use std::fmt::Debug;
+
+pub trait Semiring: Copy + Send + Sync + Debug + PartialEq {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(self, rhs: Self) -> Self;
+    fn mul(self, rhs: Self) -> Self;
+}
+
+// Tropical (min, +): products give shortest path lengths. zero is +inf, one is 0.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct MinPlus<T>(pub T);
+
+// Tropical (max, +): products give longest path lengths. zero is -inf, one is 0.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct MaxPlus<T>(pub T);
+
+// (max, *) over non-negative values: products give most probable paths. zero is 0, one is 1.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct MaxTimes<T>(pub T);
+
+// Boolean (or, and): products give reachability. zero is false, one is true.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrAnd(pub bool);
+
+impl Semiring for MinPlus<f32> {
+    fn zero() -> Self { MinPlus(f32::INFINITY) }
+    fn one() -> Self { MinPlus(0.0) }
+    fn add(self, rhs: Self) -> Self { MinPlus(self.0.min(rhs.0)) }
+    fn mul(self, rhs: Self) -> Self { MinPlus(self.0 + rhs.0) }
+}
+impl Semiring for MinPlus<f64> {
+    fn zero() -> Self { MinPlus(f64::INFINITY) }
+    fn one() -> Self { MinPlus(0.0) }
+    fn add(self, rhs: Self) -> Self { MinPlus(self.0.min(rhs.0)) }
+    fn mul(self, rhs: Self) -> Self { MinPlus(self.0 + rhs.0) }
+}
+impl Semiring for MaxPlus<f32> {
+    fn zero() -> Self { MaxPlus(f32::NEG_INFINITY) }
+    fn one() -> Self { MaxPlus(0.0) }
+    fn add(self, rhs: Self) -> Self { MaxPlus(self.0.max(rhs.0)) }
+    fn mul(self, rhs: Self) -> Self { MaxPlus(self.0 + rhs.0) }
+}
+impl Semiring for MaxPlus<f64> {
+    fn zero() -> Self { MaxPlus(f64::NEG_INFINITY) }
+    fn one() -> Self { MaxPlus(0.0) }
+    fn add(self, rhs: Self) -> Self { MaxPlus(self.0.max(rhs.0)) }
+    fn mul(self, rhs: Self) -> Self { MaxPlus(self.0 + rhs.0) }
+}
+impl Semiring for MaxTimes<f32> {
+    fn zero() -> Self { MaxTimes(0.0) }
+    fn one() -> Self { MaxTimes(1.0) }
+    fn add(self, rhs: Self) -> Self { MaxTimes(self.0.max(rhs.0)) }
+    fn mul(self, rhs: Self) -> Self { MaxTimes(self.0 * rhs.0) }
+}
+impl Semiring for MaxTimes<f64> {
+    fn zero() -> Self { MaxTimes(0.0) }
+    fn one() -> Self { MaxTimes(1.0) }
+    fn add(self, rhs: Self) -> Self { MaxTimes(self.0.max(rhs.0)) }
+    fn mul(self, rhs: Self) -> Self { MaxTimes(self.0 * rhs.0) }
+}
+impl Semiring for OrAnd {
+    fn zero() -> Self { OrAnd(false) }
+    fn one() -> Self { OrAnd(true) }
+    fn add(self, rhs: Self) -> Self { OrAnd(self.0 || rhs.0) }
+    fn mul(self, rhs: Self) -> Self { OrAnd(self.0 && rhs.0) }
+}
@@ -0,0 +1,10 @@
+// Compact storage formats for structured matrices, used by the Level 2 and 3 routines.
+pub mod band;
+pub mod packed;
+pub mod sym_band;
+
+// Sparse formats: coordinate for assembly, compressed row and column for computation.
+pub mod coo;
+pub mod csr;
+pub mod csc;
+pub mod sparse_vec;
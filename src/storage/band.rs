@@ -0,0 +1,120 @@
+use crate::scalar::Scalar;
+
+// General m x n band matrix with kl sub-diagonals and ku super-diagonals in LAPACK band
+// layout: column j is stored contiguously and A(i, j) lives at data[ku + i - j + j * ldab],
+// with ldab = kl + ku + 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandMatrix<T> {
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    data: Vec<T>,
+}
+
+impl<T: Scalar> BandMatrix<T> {
+    pub fn new(m: usize, n: usize, kl: usize, ku: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), (kl + ku + 1) * n, "band data must hold (kl + ku + 1) * n elements");
+        BandMatrix { m, n, kl, ku, data }
+    }
+
+    pub fn zeros(m: usize, n: usize, kl: usize, ku: usize) -> Self {
+        Self::new(m, n, kl, ku, vec![T::zero(); (kl + ku + 1) * n])
+    }
+
+    // Copies the band of a column-major dense matrix; entries outside the band are dropped.
+    pub fn from_dense(m: usize, n: usize, kl: usize, ku: usize, a: &[T], lda: usize) -> Self {
+        let mut band = Self::zeros(m, n, kl, ku);
+        for j in 0..n {
+            for i in band.col_range(j) {
+                band.set(i, j, a[i + j * lda]);
+            }
+        }
+        band
+    }
+
+    pub fn to_dense(&self) -> Vec<T> {
+        let mut a = vec![T::zero(); self.m * self.n];
+        for j in 0..self.n {
+            for i in self.col_range(j) {
+                a[i + j * self.m] = self.get(i, j);
+            }
+        }
+        a
+    }
+
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    pub fn cols(&self) -> usize {
+        self.n
+    }
+
+    pub fn kl(&self) -> usize {
+        self.kl
+    }
+
+    pub fn ku(&self) -> usize {
+        self.ku
+    }
+
+    pub fn ldab(&self) -> usize {
+        self.kl + self.ku + 1
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    // Rows of column j that fall inside the band.
+    pub fn col_range(&self, j: usize) -> std::ops::Range<usize> {
+        j.saturating_sub(self.ku)..(j + self.kl + 1).min(self.m)
+    }
+
+    // Columns of row i that fall inside the band.
+    pub fn row_range(&self, i: usize) -> std::ops::Range<usize> {
+        i.saturating_sub(self.kl)..(i + self.ku + 1).min(self.n)
+    }
+
+    // Returns zero outside the band or the matrix.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i < self.m && j < self.n && self.col_range(j).contains(&i) {
+            self.data[self.ku + i - j + j * self.ldab()]
+        } else {
+            T::zero()
+        }
+    }
+
+    pub fn set(&mut self, i: usize, j: usize, value: T) {
+        assert!(i < self.m && j < self.n && self.col_range(j).contains(&i), "({i}, {j}) is outside the band");
+        let ldab = self.ldab();
+        self.data[self.ku + i - j + j * ldab] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_band_matrix() {
+        // 4 x 3 with one sub- and one super-diagonal
+        let dense: Vec<f64> = vec![
+            1.0, 2.0, 0.0, 0.0,
+            3.0, 4.0, 5.0, 0.0,
+            0.0, 6.0, 7.0, 8.0,
+        ];
+        let band = BandMatrix::from_dense(4, 3, 1, 1, &dense, 4);
+        assert_eq!(band.as_slice(), &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(band.get(3, 2), 8.0);
+        assert_eq!(band.get(3, 0), 0.0);
+        // Column 3 is past the matrix, though row 3 would fall inside its band
+        assert_eq!(band.get(3, 3), 0.0);
+        assert_eq!(band.to_dense(), dense);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::scalar::Scalar;
+use crate::storage::csc::CscMatrix;
+use crate::storage::csr::{compress, CsrMatrix};
+
+// Coordinate sparse matrix: entry k is values[k] at (row_indices[k], col_indices[k]). Entries
+// may appear in any order and duplicates are summed, which makes this the assembly format.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CooMatrix<T> {
+    m: usize,
+    n: usize,
+    row_indices: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Scalar> CooMatrix<T> {
+    pub fn new(m: usize, n: usize, row_indices: Vec<usize>, col_indices: Vec<usize>, values: Vec<T>) -> Self {
+        assert!(row_indices.len() == values.len() && col_indices.len() == values.len(), "row indices, column indices and values must have the same length");
+        assert!(row_indices.iter().all(|&i| i < m) && col_indices.iter().all(|&j| j < n), "index out of bounds");
+        CooMatrix { m, n, row_indices, col_indices, values }
+    }
+
+    pub fn zeros(m: usize, n: usize) -> Self {
+        Self::new(m, n, Vec::new(), Vec::new(), Vec::new())
+    }
+
+    // Stores the nonzero entries of a column-major dense matrix in column order.
+    pub fn from_dense(m: usize, n: usize, a: &[T], lda: usize) -> Self {
+        let mut coo = Self::zeros(m, n);
+        for j in 0..n {
+            for i in 0..m {
+                if a[i + j * lda] != T::zero() {
+                    coo.push(i, j, a[i + j * lda]);
+                }
+            }
+        }
+        coo
+    }
+
+    pub fn to_dense(&self) -> Vec<T> {
+        let mut a = vec![T::zero(); self.m * self.n];
+        for ((&i, &j), &v) in self.row_indices.iter().zip(&self.col_indices).zip(&self.values) {
+            a[i + j * self.m] += v;
+        }
+        a
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let (indptr, indices, values) = compress(self.m, &self.row_indices, &self.col_indices, &self.values);
+        CsrMatrix::new(self.m, self.n, indptr, indices, values)
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let (indptr, indices, values) = compress(self.n, &self.col_indices, &self.row_indices, &self.values);
+        CscMatrix::new(self.m, self.n, indptr, indices, values)
+    }
+
+    // Appends an entry; it is added to any entry already stored at (i, j).
+    pub fn push(&mut self, i: usize, j: usize, value: T) {
+        assert!(i < self.m && j < self.n, "({i}, {j}) is out of bounds");
+        self.row_indices.push(i);
+        self.col_indices.push(j);
+        self.values.push(value);
+    }
+
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    pub fn cols(&self) -> usize {
+        self.n
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn row_indices(&self) -> &[usize] {
+        &self.row_indices
+    }
+
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_coo_matrix() {
+        // Unordered entries with a duplicate at (0, 1): [[0, 3], [4, 0], [0, 5]]
+        let mut coo: CooMatrix<f64> = CooMatrix::new(3, 2, vec![2, 0, 1], vec![1, 1, 0], vec![5.0, 1.0, 4.0]);
+        coo.push(0, 1, 2.0);
+        assert_eq!(coo.nnz(), 4);
+        assert_eq!(coo.to_dense(), vec![0.0, 4.0, 0.0, 3.0, 0.0, 5.0]);
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.indptr(), &[0, 1, 2, 3]);
+        assert_eq!(csr.indices(), &[1, 0, 1]);
+        assert_eq!(csr.values(), &[3.0, 4.0, 5.0]);
+
+        let csc = coo.to_csc();
+        assert_eq!(csc.indptr(), &[0, 1, 3]);
+        assert_eq!(csc.indices(), &[1, 0, 2]);
+        assert_eq!(csc.values(), &[4.0, 3.0, 5.0]);
+
+        let dense_complex: Vec<Complex<f32>> = vec![Complex::new(0.0, 1.0), Complex::new(0.0, 0.0), Complex::new(2.0, 0.0), Complex::new(0.0, 0.0)];
+        let coo_complex = CooMatrix::from_dense(2, 2, &dense_complex, 2);
+        assert_eq!(coo_complex.row_indices(), &[0, 0]);
+        assert_eq!(coo_complex.col_indices(), &[0, 1]);
+        assert_eq!(coo_complex.to_csc().to_dense(), dense_complex);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::scalar::Scalar;
+use crate::storage::coo::CooMatrix;
+use crate::storage::csr::{check_compressed, CsrMatrix};
+
+// Compressed sparse column matrix: the entries of column j are values[indptr[j]..indptr[j + 1]],
+// in row indices[indptr[j]..indptr[j + 1]]. Row indices are strictly increasing within each
+// column, so every stored entry is unique.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CscMatrix<T> {
+    m: usize,
+    n: usize,
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Scalar> CscMatrix<T> {
+    pub fn new(m: usize, n: usize, indptr: Vec<usize>, indices: Vec<usize>, values: Vec<T>) -> Self {
+        check_compressed(n, m, &indptr, &indices, &values);
+        CscMatrix { m, n, indptr, indices, values }
+    }
+
+    pub fn zeros(m: usize, n: usize) -> Self {
+        Self::new(m, n, vec![0; n + 1], Vec::new(), Vec::new())
+    }
+
+    // Stores the nonzero entries of a column-major dense matrix.
+    pub fn from_dense(m: usize, n: usize, a: &[T], lda: usize) -> Self {
+        CooMatrix::from_dense(m, n, a, lda).to_csc()
+    }
+
+    pub fn to_dense(&self) -> Vec<T> {
+        let mut a = vec![T::zero(); self.m * self.n];
+        for j in 0..self.n {
+            let (rows, values) = self.col(j);
+            for (&i, &v) in rows.iter().zip(values) {
+                a[i + j * self.m] = v;
+            }
+        }
+        a
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        let cols = (0..self.n).flat_map(|j| std::iter::repeat_n(j, self.indptr[j + 1] - self.indptr[j])).collect();
+        CooMatrix::new(self.m, self.n, self.indices.clone(), cols, self.values.clone())
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        self.to_coo().to_csr()
+    }
+
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    pub fn cols(&self) -> usize {
+        self.n
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    // Row indices and values of the entries stored in column j.
+    pub fn col(&self, j: usize) -> (&[usize], &[T]) {
+        let range = self.indptr[j]..self.indptr[j + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    // Returns zero for entries that are not stored.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let (rows, values) = self.col(j);
+        rows.binary_search(&i).map_or(T::zero(), |k| values[k])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_csc_matrix() {
+        // [[1, 0, 2], [0, 0, 0], [0, 3, 4]]
+        let dense: Vec<f32> = vec![1.0, 0.0, 0.0, 0.0, 0.0, 3.0, 2.0, 0.0, 4.0];
+        let csc = CscMatrix::from_dense(3, 3, &dense, 3);
+        assert_eq!(csc.indptr(), &[0, 1, 2, 4]);
+        assert_eq!(csc.indices(), &[0, 2, 0, 2]);
+        assert_eq!(csc.values(), &[1.0, 3.0, 2.0, 4.0]);
+        assert_eq!(csc.get(0, 2), 2.0);
+        assert_eq!(csc.get(1, 2), 0.0);
+        assert_eq!(csc.to_dense(), dense);
+        assert_eq!(csc.to_csr().to_csc(), csc);
+        assert_eq!(csc.to_csr().to_dense(), dense);
+
+        let csc_complex: CscMatrix<Complex<f64>> = CscMatrix::new(2, 2, vec![0, 0, 2], vec![0, 1], vec![Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)]);
+        assert_eq!(csc_complex.to_coo().to_dense(), vec![Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)]);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::scalar::Scalar;
+use crate::storage::coo::CooMatrix;
+use crate::storage::csc::CscMatrix;
+
+// Compressed sparse row matrix: the entries of row i are values[indptr[i]..indptr[i + 1]],
+// in column indices[indptr[i]..indptr[i + 1]]. Column indices are strictly increasing
+// within each row, so every stored entry is unique.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMatrix<T> {
+    m: usize,
+    n: usize,
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Scalar> CsrMatrix<T> {
+    pub fn new(m: usize, n: usize, indptr: Vec<usize>, indices: Vec<usize>, values: Vec<T>) -> Self {
+        check_compressed(m, n, &indptr, &indices, &values);
+        CsrMatrix { m, n, indptr, indices, values }
+    }
+
+    pub fn zeros(m: usize, n: usize) -> Self {
+        Self::new(m, n, vec![0; m + 1], Vec::new(), Vec::new())
+    }
+
+    // Stores the nonzero entries of a column-major dense matrix.
+    pub fn from_dense(m: usize, n: usize, a: &[T], lda: usize) -> Self {
+        CooMatrix::from_dense(m, n, a, lda).to_csr()
+    }
+
+    pub fn to_dense(&self) -> Vec<T> {
+        let mut a = vec![T::zero(); self.m * self.n];
+        for i in 0..self.m {
+            let (cols, values) = self.row(i);
+            for (&j, &v) in cols.iter().zip(values) {
+                a[i + j * self.m] = v;
+            }
+        }
+        a
+    }
+
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        let rows = (0..self.m).flat_map(|i| std::iter::repeat_n(i, self.indptr[i + 1] - self.indptr[i])).collect();
+        CooMatrix::new(self.m, self.n, rows, self.indices.clone(), self.values.clone())
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        self.to_coo().to_csc()
+    }
+
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    pub fn cols(&self) -> usize {
+        self.n
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    // Column indices and values of the entries stored in row i.
+    pub fn row(&self, i: usize) -> (&[usize], &[T]) {
+        let range = self.indptr[i]..self.indptr[i + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    // Returns zero for entries that are not stored.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let (cols, values) = self.row(i);
+        cols.binary_search(&j).map_or(T::zero(), |k| values[k])
+    }
+}
+
+// Validates compressed storage with `outer` compressed slices over `inner` indices.
+pub(crate) fn check_compressed<T>(outer: usize, inner: usize, indptr: &[usize], indices: &[usize], values: &[T]) {
+    assert_eq!(indptr.len(), outer + 1, "indptr must hold one more element than the compressed dimension");
+    assert_eq!(indptr[0], 0, "indptr must start at zero");
+    assert_eq!(indices.len(), values.len(), "indices and values must have the same length");
+    assert_eq!(indptr[outer], indices.len(), "indptr must end at the number of stored entries");
+    for w in indptr.windows(2) {
+        assert!(w[0] <= w[1], "indptr must be non-decreasing");
+        let slice = &indices[w[0]..w[1]];
+        assert!(slice.windows(2).all(|p| p[0] < p[1]), "indices must be strictly increasing within each slice");
+        assert!(slice.last().is_none_or(|&k| k < inner), "index out of bounds");
+    }
+}
+
+// Builds compressed storage from unordered (major, minor, value) triplets, summing duplicates.
+// Entries are bucketed by major index with a counting sort, then sorted by minor index.
+pub(crate) fn compress<T: Scalar>(outer: usize, major: &[usize], minor: &[usize], values: &[T]) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    let mut start = vec![0; outer + 1];
+    for &o in major {
+        start[o + 1] += 1;
+    }
+    for o in 0..outer {
+        start[o + 1] += start[o];
+    }
+    let mut next = start.clone();
+    let mut order = vec![0; major.len()];
+    for (k, &o) in major.iter().enumerate() {
+        order[next[o]] = k;
+        next[o] += 1;
+    }
+    let mut indptr = Vec::with_capacity(outer + 1);
+    let mut indices = Vec::with_capacity(major.len());
+    let mut merged = Vec::with_capacity(major.len());
+    indptr.push(0);
+    for w in start.windows(2) {
+        let slice = &mut order[w[0]..w[1]];
+        slice.sort_by_key(|&k| minor[k]);
+        let first = indices.len();
+        for &k in slice.iter() {
+            if indices.len() > first && indices.last() == Some(&minor[k]) {
+                *merged.last_mut().unwrap() += values[k];
+            } else {
+                indices.push(minor[k]);
+                merged.push(values[k]);
+            }
+        }
+        indptr.push(indices.len());
+    }
+    (indptr, indices, merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_csr_matrix() {
+        // [[1, 0, 2], [0, 0, 0], [0, 3, 4]]
+        let dense: Vec<f64> = vec![1.0, 0.0, 0.0, 0.0, 0.0, 3.0, 2.0, 0.0, 4.0];
+        let csr = CsrMatrix::from_dense(3, 3, &dense, 3);
+        assert_eq!(csr.indptr(), &[0, 2, 2, 4]);
+        assert_eq!(csr.indices(), &[0, 2, 1, 2]);
+        assert_eq!(csr.values(), &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(csr.get(2, 1), 3.0);
+        assert_eq!(csr.get(1, 1), 0.0);
+        assert_eq!(csr.to_dense(), dense);
+        assert_eq!(csr.to_csc().to_csr(), csr);
+        assert_eq!(csr.to_coo().to_csr(), csr);
+
+        let csr_complex: CsrMatrix<Complex<f32>> = CsrMatrix::new(2, 2, vec![0, 1, 2], vec![1, 0], vec![Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)]);
+        assert_eq!(csr_complex.to_dense(), vec![Complex::new(0.0, 0.0), Complex::new(0.0, 2.0), Complex::new(1.0, 1.0), Complex::new(0.0, 0.0)]);
+        assert_eq!(CsrMatrix::<f32>::zeros(2, 3).to_dense(), vec![0.0; 6]);
+    }
+}
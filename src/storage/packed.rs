@@ -0,0 +1,147 @@
+use crate::scalar::Scalar;
+use crate::types::Uplo;
+
+// n x n symmetric, Hermitian or triangular matrix with only the `uplo` triangle stored,
+// column by column, in n * (n + 1) / 2 elements (BLAS packed layout):
+// Upper: A(i, j), i <= j, at data[i + j * (j + 1) / 2]
+// Lower: A(i, j), i >= j, at data[i + j * (2 * n - j - 1) / 2]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackedMatrix<T> {
+    n: usize,
+    uplo: Uplo,
+    data: Vec<T>,
+}
+
+impl<T: Scalar> PackedMatrix<T> {
+    pub fn new(uplo: Uplo, n: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), n * (n + 1) / 2, "packed data must hold n * (n + 1) / 2 elements");
+        PackedMatrix { n, uplo, data }
+    }
+
+    pub fn zeros(uplo: Uplo, n: usize) -> Self {
+        Self::new(uplo, n, vec![T::zero(); n * (n + 1) / 2])
+    }
+
+    // Packs the `uplo` triangle of a column-major dense matrix; the other triangle is not read.
+    pub fn from_dense(uplo: Uplo, n: usize, a: &[T], lda: usize) -> Self {
+        let mut ap = Self::zeros(uplo, n);
+        for (j, col) in ap.columns_mut() {
+            let rows = match uplo {
+                Uplo::Upper => 0..j + 1,
+                Uplo::Lower => j..n,
+            };
+            col.copy_from_slice(&a[rows.start + j * lda..rows.end + j * lda]);
+        }
+        ap
+    }
+
+    // Full dense matrix with the missing triangle mirrored from the stored one.
+    pub fn to_dense_symmetric(&self) -> Vec<T> {
+        self.to_dense(false)
+    }
+
+    // Full dense matrix with the missing triangle mirrored and conjugated.
+    pub fn to_dense_hermitian(&self) -> Vec<T> {
+        self.to_dense(true)
+    }
+
+    fn to_dense(&self, conj: bool) -> Vec<T> {
+        let n = self.n;
+        let mut a = vec![T::zero(); n * n];
+        for j in 0..n {
+            for i in self.col_range(j) {
+                let v = self.data[self.index(i, j)];
+                a[i + j * n] = v;
+                a[j + i * n] = if conj { v.conj() } else { v };
+            }
+        }
+        a
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn uplo(&self) -> Uplo {
+        self.uplo
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    // Rows of column j held in storage.
+    pub fn col_range(&self, j: usize) -> std::ops::Range<usize> {
+        match self.uplo {
+            Uplo::Upper => 0..j + 1,
+            Uplo::Lower => j..self.n,
+        }
+    }
+
+    // Position of A(i, j) in the packed data; (i, j) must lie in the stored triangle.
+    pub fn index(&self, i: usize, j: usize) -> usize {
+        match self.uplo {
+            Uplo::Upper => {
+                debug_assert!(i <= j);
+                i + j * (j + 1) / 2
+            }
+            Uplo::Lower => {
+                debug_assert!(i >= j && i < self.n);
+                i + j * (2 * self.n - j - 1) / 2
+            }
+        }
+    }
+
+    // A(i, j) read through the stored triangle, i.e. the symmetric view of the matrix.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let stored = match self.uplo {
+            Uplo::Upper => i <= j,
+            Uplo::Lower => i >= j,
+        };
+        if stored { self.data[self.index(i, j)] } else { self.data[self.index(j, i)] }
+    }
+
+    // Splits the storage into one mutable slice per column, each covering `col_range(j)`.
+    pub(crate) fn columns_mut(&mut self) -> Vec<(usize, &mut [T])> {
+        let n = self.n;
+        let uplo = self.uplo;
+        let mut rest = self.data.as_mut_slice();
+        let mut cols = Vec::with_capacity(n);
+        for j in 0..n {
+            let len = match uplo {
+                Uplo::Upper => j + 1,
+                Uplo::Lower => n - j,
+            };
+            let (col, tail) = rest.split_at_mut(len);
+            cols.push((j, col));
+            rest = tail;
+        }
+        cols
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_packed_matrix() {
+        let dense: Vec<f64> = vec![1.0, 2.0, 3.0, 2.0, 4.0, 5.0, 3.0, 5.0, 6.0];
+        let upper = PackedMatrix::from_dense(Uplo::Upper, 3, &dense, 3);
+        let lower = PackedMatrix::from_dense(Uplo::Lower, 3, &dense, 3);
+        assert_eq!(upper.as_slice(), &[1.0, 2.0, 4.0, 3.0, 5.0, 6.0]);
+        assert_eq!(lower.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(upper.get(2, 1), 5.0);
+        assert_eq!(lower.get(1, 2), 5.0);
+        assert_eq!(upper.to_dense_symmetric(), dense);
+        assert_eq!(lower.to_dense_symmetric(), dense);
+
+        let hermitian = PackedMatrix::new(Uplo::Lower, 2, vec![Complex::new(1.0, 0.0), Complex::new(2.0, 3.0), Complex::new(4.0, 0.0)]);
+        assert_eq!(hermitian.to_dense_hermitian(), vec![Complex::new(1.0, 0.0), Complex::new(2.0, 3.0), Complex::new(2.0, -3.0), Complex::new(4.0, 0.0)]);
+    }
+}
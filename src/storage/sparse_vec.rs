@@ -0,0 +1,114 @@
+use crate::scalar::Scalar;
+
+// Stored elements below which a scatter is applied serially.
+const GRAIN: usize = 4096;
+
+// Sparse vector of dimension n: values[k] is element indices[k]. Indices are strictly
+// increasing, so every stored element is unique and scatters never collide.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseVec<T> {
+    n: usize,
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Scalar> SparseVec<T> {
+    pub fn new(n: usize, indices: Vec<usize>, values: Vec<T>) -> Self {
+        assert_eq!(indices.len(), values.len(), "indices and values must have the same length");
+        assert!(indices.windows(2).all(|p| p[0] < p[1]), "indices must be strictly increasing");
+        assert!(indices.last().is_none_or(|&i| i < n), "index out of bounds");
+        SparseVec { n, indices, values }
+    }
+
+    pub fn zeros(n: usize) -> Self {
+        Self::new(n, Vec::new(), Vec::new())
+    }
+
+    // Stores the nonzero elements of a strided dense vector of dimension n.
+    pub fn from_dense(n: usize, x: &[T], incx: usize) -> Self {
+        let (indices, values) = x
+        .iter()
+        .step_by(incx)
+        .take(n)
+        .enumerate()
+        .filter(|&(_, &v)| v != T::zero())
+        .unzip();
+        Self::new(n, indices, values)
+    }
+
+    pub fn to_dense(&self) -> Vec<T> {
+        let mut x = vec![T::zero(); self.n];
+        for (&i, &v) in self.indices.iter().zip(&self.values) {
+            x[i] = v;
+        }
+        x
+    }
+
+    pub fn dim(&self) -> usize {
+        self.n
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    // Indices alongside mutable values, for filling the values of a fixed pattern.
+    pub fn parts_mut(&mut self) -> (&[usize], &mut [T]) {
+        (&self.indices, &mut self.values)
+    }
+
+    // Calls f(&mut y[indices[k] * incy], values[k]) for every stored element, with incy > 0.
+    // Strictly increasing indices make the targets disjoint, so y is split at the middle
+    // stored element and the two halves are processed in parallel.
+    pub(crate) fn par_scatter<F: Fn(&mut T, T) + Sync>(&self, y: &mut [T], incy: usize, f: F) {
+        scatter(&self.indices, &self.values, 0, y, incy, &f)
+    }
+}
+
+// `par_scatter` over the elements whose targets lie in y, which starts at offset of the full y.
+fn scatter<T: Scalar, F: Fn(&mut T, T) + Sync>(indices: &[usize], values: &[T], offset: usize, y: &mut [T], incy: usize, f: &F) {
+    if indices.len() <= GRAIN {
+        for (&i, &v) in indices.iter().zip(values) {
+            f(&mut y[i * incy - offset], v);
+        }
+        return;
+    }
+    let mid = indices.len() / 2;
+    let split = indices[mid] * incy - offset;
+    let (y_lo, y_hi) = y.split_at_mut(split);
+    rayon::join(
+        || scatter(&indices[..mid], &values[..mid], offset, y_lo, incy, f),
+        || scatter(&indices[mid..], &values[mid..], offset + split, y_hi, incy, f),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_vec() {
+        let dense: Vec<f64> = vec![0.0, 1.0, 0.0, 0.0, 2.0];
+        let x = SparseVec::from_dense(5, &dense, 1);
+        assert_eq!(x.indices(), &[1, 4]);
+        assert_eq!(x.values(), &[1.0, 2.0]);
+        assert_eq!(x.to_dense(), dense);
+
+        let strided = SparseVec::from_dense(3, &dense, 2);
+        assert_eq!(strided.indices(), &[2]);
+        assert_eq!(strided.values(), &[2.0]);
+        assert_eq!(SparseVec::<f32>::zeros(3).to_dense(), vec![0.0; 3]);
+    }
+}
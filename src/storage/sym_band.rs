@@ -0,0 +1,130 @@
+use crate::scalar::Scalar;
+use crate::types::Uplo;
+
+// n x n symmetric or Hermitian band matrix with k off-diagonals, storing only the `uplo`
+// triangle in LAPACK band layout with ldab = k + 1:
+// Upper: A(i, j), j - k <= i <= j, at data[k + i - j + j * ldab]
+// Lower: A(i, j), j <= i <= j + k, at data[i - j + j * ldab]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymBandMatrix<T> {
+    n: usize,
+    k: usize,
+    uplo: Uplo,
+    data: Vec<T>,
+}
+
+impl<T: Scalar> SymBandMatrix<T> {
+    pub fn new(uplo: Uplo, n: usize, k: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), (k + 1) * n, "band data must hold (k + 1) * n elements");
+        SymBandMatrix { n, k, uplo, data }
+    }
+
+    pub fn zeros(uplo: Uplo, n: usize, k: usize) -> Self {
+        Self::new(uplo, n, k, vec![T::zero(); (k + 1) * n])
+    }
+
+    // Copies the `uplo` triangle of the band of a column-major dense matrix.
+    pub fn from_dense(uplo: Uplo, n: usize, k: usize, a: &[T], lda: usize) -> Self {
+        let mut band = Self::zeros(uplo, n, k);
+        for j in 0..n {
+            for i in band.col_range(j) {
+                let idx = band.index(i, j);
+                band.data[idx] = a[i + j * lda];
+            }
+        }
+        band
+    }
+
+    // Full dense matrix with the missing triangle mirrored from the stored one.
+    pub fn to_dense_symmetric(&self) -> Vec<T> {
+        self.to_dense(false)
+    }
+
+    // Full dense matrix with the missing triangle mirrored and conjugated.
+    pub fn to_dense_hermitian(&self) -> Vec<T> {
+        self.to_dense(true)
+    }
+
+    fn to_dense(&self, conj: bool) -> Vec<T> {
+        let n = self.n;
+        let mut a = vec![T::zero(); n * n];
+        for j in 0..n {
+            for i in self.col_range(j) {
+                let v = self.data[self.index(i, j)];
+                a[i + j * n] = v;
+                a[j + i * n] = if conj { v.conj() } else { v };
+            }
+        }
+        a
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    pub fn uplo(&self) -> Uplo {
+        self.uplo
+    }
+
+    pub fn ldab(&self) -> usize {
+        self.k + 1
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    // Rows of column j held in storage.
+    pub fn col_range(&self, j: usize) -> std::ops::Range<usize> {
+        match self.uplo {
+            Uplo::Upper => j.saturating_sub(self.k)..j + 1,
+            Uplo::Lower => j..(j + self.k + 1).min(self.n),
+        }
+    }
+
+    // Position of A(i, j) in the band data; (i, j) must lie in the stored part of the band.
+    pub fn index(&self, i: usize, j: usize) -> usize {
+        debug_assert!(self.col_range(j).contains(&i));
+        match self.uplo {
+            Uplo::Upper => self.k + i - j + j * self.ldab(),
+            Uplo::Lower => i - j + j * self.ldab(),
+        }
+    }
+
+    // A(i, j) read through the stored triangle, i.e. the symmetric view; zero outside the band.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let (i, j) = match self.uplo {
+            Uplo::Upper if i > j => (j, i),
+            Uplo::Lower if i < j => (j, i),
+            _ => (i, j),
+        };
+        if j < self.n && self.col_range(j).contains(&i) { self.data[self.index(i, j)] } else { T::zero() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sym_band_matrix() {
+        // Symmetric tridiagonal [[1, 2, 0], [2, 3, 4], [0, 4, 5]]
+        let dense: Vec<f64> = vec![1.0, 2.0, 0.0, 2.0, 3.0, 4.0, 0.0, 4.0, 5.0];
+        let upper = SymBandMatrix::from_dense(Uplo::Upper, 3, 1, &dense, 3);
+        let lower = SymBandMatrix::from_dense(Uplo::Lower, 3, 1, &dense, 3);
+        assert_eq!(upper.as_slice(), &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(lower.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 0.0]);
+        assert_eq!(upper.get(2, 1), 4.0);
+        assert_eq!(lower.get(0, 2), 0.0);
+        assert_eq!(upper.to_dense_symmetric(), dense);
+        assert_eq!(lower.to_dense_symmetric(), dense);
+    }
+}
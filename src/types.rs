@@ -0,0 +1,36 @@
+// Option flags shared by the matrix routines, mirroring the CBLAS enums.
+use crate::scalar::Scalar;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trans {
+    NoTrans,
+    Trans,
+    ConjTrans,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Uplo {
+    Upper,
+    Lower,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Diag {
+    Unit,
+    NonUnit,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+// Element (i, j) of op(A) for a column-major A with leading dimension ld.
+pub(crate) fn op_at<T: Scalar>(a: &[T], trans: Trans, ld: usize, i: usize, j: usize) -> T {
+    match trans {
+        Trans::NoTrans => a[i + j * ld],
+        Trans::Trans => a[j + i * ld],
+        Trans::ConjTrans => a[j + i * ld].conj(),
+    }
+}